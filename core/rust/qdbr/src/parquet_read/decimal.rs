@@ -17,6 +17,12 @@ use std::ptr;
 
 /// Decode a FixedLenByteArray with Decimal logical type to a QuestDB decimal column.
 /// Handles all source sizes (1-32 bytes) and target decimal types (Decimal8-Decimal256).
+/// `src_scale` is the scale of the Parquet decimal logical type; `target_scale` is the
+/// scale of the destination column. When they differ the decoded unscaled integers are
+/// rescaled in place after decoding (see `rescale_decimal_in_place`), and `overflow_policy`
+/// governs both the initial narrowing conversion and the rescale step. In the narrowing
+/// conversion (source wider than the target) `Saturate` writes the null sentinel like `Null`
+/// and does not clamp; only the rescale step clamps under `Saturate`.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn decode_fixed_decimal(
     page: &DataPage,
@@ -26,7 +32,10 @@ pub(crate) fn decode_fixed_decimal(
     row_hi: usize,
     row_count: usize,
     src_len: usize,
+    src_scale: i32,
+    target_scale: i32,
     target_tag: ColumnTypeTag,
+    overflow_policy: DecimalOverflowPolicy,
 ) -> ParquetResult<()> {
     let target_size = match target_tag {
         ColumnTypeTag::Decimal8 => 1,
@@ -54,6 +63,7 @@ pub(crate) fn decode_fixed_decimal(
         ));
     }
 
+    let rows_before = bufs.data_vec.len();
     match target_tag {
         ColumnTypeTag::Decimal8 => decode_fixed_decimal_1(
             page,
@@ -63,6 +73,7 @@ pub(crate) fn decode_fixed_decimal(
             row_hi,
             row_count,
             src_len,
+            overflow_policy,
         ),
         ColumnTypeTag::Decimal16 => decode_fixed_decimal_2(
             page,
@@ -72,6 +83,7 @@ pub(crate) fn decode_fixed_decimal(
             row_hi,
             row_count,
             src_len,
+            overflow_policy,
         ),
         ColumnTypeTag::Decimal32 => decode_fixed_decimal_4(
             page,
@@ -81,6 +93,7 @@ pub(crate) fn decode_fixed_decimal(
             row_hi,
             row_count,
             src_len,
+            overflow_policy,
         ),
         ColumnTypeTag::Decimal64 => decode_fixed_decimal_8(
             page,
@@ -90,6 +103,7 @@ pub(crate) fn decode_fixed_decimal(
             row_hi,
             row_count,
             src_len,
+            overflow_policy,
         ),
         ColumnTypeTag::Decimal128 => decode_fixed_decimal_16(
             page,
@@ -99,6 +113,7 @@ pub(crate) fn decode_fixed_decimal(
             row_hi,
             row_count,
             src_len,
+            overflow_policy,
         ),
         ColumnTypeTag::Decimal256 => decode_fixed_decimal_32(
             page,
@@ -108,13 +123,27 @@ pub(crate) fn decode_fixed_decimal(
             row_hi,
             row_count,
             src_len,
+            overflow_policy,
         ),
         _ => Err(fmt_err!(
             Unsupported,
             "unsupported target column type {:?} for FixedLenByteArray decimal",
             target_tag
         )),
+    }?;
+
+    if src_scale != target_scale {
+        rescale_new_rows(
+            bufs,
+            rows_before,
+            target_size,
+            src_scale,
+            target_scale,
+            decimal_null_bytes(target_tag),
+            overflow_policy,
+        )?;
     }
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -127,7 +156,10 @@ pub(crate) fn decode_fixed_decimal_dict(
     row_hi: usize,
     row_count: usize,
     src_len: usize,
+    src_scale: i32,
+    target_scale: i32,
     target_tag: ColumnTypeTag,
+    overflow_policy: DecimalOverflowPolicy,
 ) -> ParquetResult<()> {
     let dict_decoder = RuntimeFixedDictDecoder::try_new(dict_page, src_len)?;
     let error_value = vec![0u8; src_len];
@@ -138,7 +170,29 @@ pub(crate) fn decode_fixed_decimal_dict(
         row_count,
         error_value.as_slice(),
     )?;
-    decode_fixed_decimal_with_slicer(page, bufs, &mut slicer, row_lo, row_hi, src_len, target_tag)
+    let rows_before = bufs.data_vec.len();
+    decode_fixed_decimal_with_slicer(
+        page,
+        bufs,
+        &mut slicer,
+        row_lo,
+        row_hi,
+        src_len,
+        target_tag,
+        overflow_policy,
+    )?;
+    if src_scale != target_scale {
+        rescale_new_rows(
+            bufs,
+            rows_before,
+            decimal_target_size(target_tag)?,
+            src_scale,
+            target_scale,
+            decimal_null_bytes(target_tag),
+            overflow_policy,
+        )?;
+    }
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -149,10 +203,34 @@ pub(crate) fn decode_byte_array_decimal(
     row_lo: usize,
     row_hi: usize,
     row_count: usize,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
     target_tag: ColumnTypeTag,
 ) -> ParquetResult<()> {
     let mut slicer = PlainVarSlicer::new(values_buffer, row_count);
-    decode_byte_array_decimal_with_slicer(page, bufs, &mut slicer, row_lo, row_hi, target_tag)
+    let rows_before = bufs.data_vec.len();
+    decode_byte_array_decimal_with_slicer(
+        page,
+        bufs,
+        &mut slicer,
+        row_lo,
+        row_hi,
+        overflow_policy,
+        target_tag,
+    )?;
+    if src_scale != target_scale {
+        rescale_new_rows(
+            bufs,
+            rows_before,
+            decimal_target_size(target_tag)?,
+            src_scale,
+            target_scale,
+            decimal_null_bytes(target_tag),
+            overflow_policy,
+        )?;
+    }
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -164,6 +242,9 @@ pub(crate) fn decode_byte_array_decimal_dict(
     row_lo: usize,
     row_hi: usize,
     row_count: usize,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
     target_tag: ColumnTypeTag,
 ) -> ParquetResult<()> {
     let dict_decoder = VarDictDecoder::try_new(dict_page, false)?;
@@ -174,7 +255,28 @@ pub(crate) fn decode_byte_array_decimal_dict(
         row_count,
         &DECIMAL_DICT_ERROR_VALUE,
     )?;
-    decode_byte_array_decimal_with_slicer(page, bufs, &mut slicer, row_lo, row_hi, target_tag)
+    let rows_before = bufs.data_vec.len();
+    decode_byte_array_decimal_with_slicer(
+        page,
+        bufs,
+        &mut slicer,
+        row_lo,
+        row_hi,
+        overflow_policy,
+        target_tag,
+    )?;
+    if src_scale != target_scale {
+        rescale_new_rows(
+            bufs,
+            rows_before,
+            decimal_target_size(target_tag)?,
+            src_scale,
+            target_scale,
+            decimal_null_bytes(target_tag),
+            overflow_policy,
+        )?;
+    }
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -188,9 +290,13 @@ pub(crate) fn decode_byte_array_decimal_filtered<const FILL_NULLS: bool>(
     row_lo: usize,
     row_hi: usize,
     rows_filter: &[i64],
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
     target_tag: ColumnTypeTag,
 ) -> ParquetResult<()> {
     let mut slicer = PlainVarSlicer::new(values_buffer, page_row_count);
+    let rows_before = bufs.data_vec.len();
     decode_byte_array_decimal_filtered_with_slicer::<FILL_NULLS, _>(
         page,
         bufs,
@@ -201,8 +307,21 @@ pub(crate) fn decode_byte_array_decimal_filtered<const FILL_NULLS: bool>(
         row_lo,
         row_hi,
         rows_filter,
+        overflow_policy,
         target_tag,
-    )
+    )?;
+    if src_scale != target_scale {
+        rescale_new_rows(
+            bufs,
+            rows_before,
+            decimal_target_size(target_tag)?,
+            src_scale,
+            target_scale,
+            decimal_null_bytes(target_tag),
+            overflow_policy,
+        )?;
+    }
+    Ok(())
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -217,6 +336,9 @@ pub(crate) fn decode_byte_array_decimal_filtered_dict<const FILL_NULLS: bool>(
     row_lo: usize,
     row_hi: usize,
     rows_filter: &[i64],
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
     target_tag: ColumnTypeTag,
 ) -> ParquetResult<()> {
     let dict_decoder = VarDictDecoder::try_new(dict_page, false)?;
@@ -227,6 +349,7 @@ pub(crate) fn decode_byte_array_decimal_filtered_dict<const FILL_NULLS: bool>(
         page_row_count,
         &DECIMAL_DICT_ERROR_VALUE,
     )?;
+    let rows_before = bufs.data_vec.len();
     decode_byte_array_decimal_filtered_with_slicer::<FILL_NULLS, _>(
         page,
         bufs,
@@ -237,168 +360,1669 @@ pub(crate) fn decode_byte_array_decimal_filtered_dict<const FILL_NULLS: bool>(
         row_lo,
         row_hi,
         rows_filter,
+        overflow_policy,
         target_tag,
-    )
+    )?;
+    if src_scale != target_scale {
+        rescale_new_rows(
+            bufs,
+            rows_before,
+            decimal_target_size(target_tag)?,
+            src_scale,
+            target_scale,
+            decimal_null_bytes(target_tag),
+            overflow_policy,
+        )?;
+    }
+    Ok(())
 }
 
-const DECIMAL_DICT_ERROR_VALUE: [u8; 1] = [0u8];
+/// ByteArray counterpart of `decode_fixed_decimal_bitmap_filtered`: every row converts to a
+/// fixed `target_size`-byte element regardless of its variable source length, so the same
+/// bitmap compaction kernel applies once the page has been decoded unfiltered into the staging
+/// region of `bufs.data_vec`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_byte_array_decimal_bitmap_filtered(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_count: usize,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    mask: &[u8],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let target_size = decimal_target_size(target_tag)?;
+    let stage_start = bufs.data_vec.len();
+    decode_byte_array_decimal(
+        page,
+        bufs,
+        values_buffer,
+        0,
+        page_row_count,
+        page_row_count,
+        src_scale,
+        target_scale,
+        overflow_policy,
+        target_tag,
+    )?;
+    compact_by_bitmap(bufs, stage_start, page_row_count, target_size, mask);
+    Ok(())
+}
 
-fn decode_byte_array_decimal_with_slicer<T: DataPageSlicer>(
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_byte_array_decimal_bitmap_filtered_dict(
     page: &DataPage,
+    dict_page: &DictPage,
     bufs: &mut ColumnChunkBuffers,
-    slicer: &mut T,
+    values_buffer: &[u8],
+    page_row_count: usize,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    mask: &[u8],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let target_size = decimal_target_size(target_tag)?;
+    let stage_start = bufs.data_vec.len();
+    decode_byte_array_decimal_dict(
+        page,
+        dict_page,
+        bufs,
+        values_buffer,
+        0,
+        page_row_count,
+        page_row_count,
+        src_scale,
+        target_scale,
+        overflow_policy,
+        target_tag,
+    )?;
+    compact_by_bitmap(bufs, stage_start, page_row_count, target_size, mask);
+    Ok(())
+}
+
+/// Decode an INT32 physical type with Decimal logical type (precision <= 9 per the Parquet
+/// spec) to a QuestDB decimal column, sign-extending the little-endian 4-byte integer into
+/// the target width.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int32_decimal(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
     row_lo: usize,
     row_hi: usize,
+    row_count: usize,
     target_tag: ColumnTypeTag,
 ) -> ParquetResult<()> {
-    match target_tag {
-        ColumnTypeTag::Decimal8 => decode_page0(
-            page,
-            row_lo,
-            row_hi,
-            &mut ByteArrayDecimalColumnSink::<1, _>::new(slicer, bufs, DECIMAL8_NULL),
-        ),
-        ColumnTypeTag::Decimal16 => decode_page0(
-            page,
-            row_lo,
-            row_hi,
-            &mut ByteArrayDecimalColumnSink::<2, _>::new(slicer, bufs, DECIMAL16_NULL),
-        ),
-        ColumnTypeTag::Decimal32 => decode_page0(
-            page,
-            row_lo,
-            row_hi,
-            &mut ByteArrayDecimalColumnSink::<4, _>::new(slicer, bufs, DECIMAL32_NULL),
-        ),
-        ColumnTypeTag::Decimal64 => decode_page0(
-            page,
-            row_lo,
-            row_hi,
-            &mut ByteArrayDecimalColumnSink::<8, _>::new(slicer, bufs, DECIMAL64_NULL),
-        ),
-        ColumnTypeTag::Decimal128 => decode_page0(
-            page,
-            row_lo,
-            row_hi,
-            &mut ByteArrayDecimalColumnSink::<16, _>::new(slicer, bufs, DECIMAL128_NULL),
-        ),
-        ColumnTypeTag::Decimal256 => decode_page0(
-            page,
-            row_lo,
-            row_hi,
-            &mut ByteArrayDecimalColumnSink::<32, _>::new(slicer, bufs, DECIMAL256_NULL),
-        ),
-        _ => Err(fmt_err!(
-            Unsupported,
-            "unsupported target column type {:?} for ByteArray decimal",
-            target_tag
-        )),
-    }
+    let mut slicer = DataPageDynSlicer::new(values_buffer, row_count, 4);
+    decode_int_decimal_with_slicer::<4, _>(page, bufs, &mut slicer, row_lo, row_hi, target_tag)
 }
 
+/// Decode an INT64 physical type with Decimal logical type (precision <= 18 per the Parquet
+/// spec) to a QuestDB decimal column, sign-extending the little-endian 8-byte integer into
+/// the target width.
 #[allow(clippy::too_many_arguments)]
-fn decode_byte_array_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPageSlicer>(
+pub(crate) fn decode_int64_decimal(
     page: &DataPage,
     bufs: &mut ColumnChunkBuffers,
-    slicer: &mut T,
-    page_row_start: usize,
-    page_row_count: usize,
-    row_group_lo: usize,
+    values_buffer: &[u8],
     row_lo: usize,
     row_hi: usize,
-    rows_filter: &[i64],
+    row_count: usize,
     target_tag: ColumnTypeTag,
 ) -> ParquetResult<()> {
-    match target_tag {
-        ColumnTypeTag::Decimal8 => decode_page0_filtered::<_, FILL_NULLS>(
-            page,
-            page_row_start,
-            page_row_count,
-            row_group_lo,
-            row_lo,
-            row_hi,
-            rows_filter,
-            &mut ByteArrayDecimalColumnSink::<1, _>::new(slicer, bufs, DECIMAL8_NULL),
-        ),
-        ColumnTypeTag::Decimal16 => decode_page0_filtered::<_, FILL_NULLS>(
-            page,
-            page_row_start,
-            page_row_count,
-            row_group_lo,
-            row_lo,
-            row_hi,
-            rows_filter,
-            &mut ByteArrayDecimalColumnSink::<2, _>::new(slicer, bufs, DECIMAL16_NULL),
-        ),
-        ColumnTypeTag::Decimal32 => decode_page0_filtered::<_, FILL_NULLS>(
-            page,
-            page_row_start,
-            page_row_count,
-            row_group_lo,
-            row_lo,
-            row_hi,
-            rows_filter,
-            &mut ByteArrayDecimalColumnSink::<4, _>::new(slicer, bufs, DECIMAL32_NULL),
-        ),
-        ColumnTypeTag::Decimal64 => decode_page0_filtered::<_, FILL_NULLS>(
-            page,
-            page_row_start,
-            page_row_count,
-            row_group_lo,
-            row_lo,
-            row_hi,
-            rows_filter,
-            &mut ByteArrayDecimalColumnSink::<8, _>::new(slicer, bufs, DECIMAL64_NULL),
-        ),
-        ColumnTypeTag::Decimal128 => decode_page0_filtered::<_, FILL_NULLS>(
-            page,
-            page_row_start,
-            page_row_count,
-            row_group_lo,
-            row_lo,
-            row_hi,
-            rows_filter,
-            &mut ByteArrayDecimalColumnSink::<16, _>::new(slicer, bufs, DECIMAL128_NULL),
-        ),
-        ColumnTypeTag::Decimal256 => decode_page0_filtered::<_, FILL_NULLS>(
-            page,
-            page_row_start,
-            page_row_count,
-            row_group_lo,
-            row_lo,
-            row_hi,
-            rows_filter,
-            &mut ByteArrayDecimalColumnSink::<32, _>::new(slicer, bufs, DECIMAL256_NULL),
-        ),
-        _ => Err(fmt_err!(
-            Unsupported,
-            "unsupported target column type {:?} for ByteArray decimal",
-            target_tag
-        )),
-    }
+    let mut slicer = DataPageDynSlicer::new(values_buffer, row_count, 8);
+    decode_int_decimal_with_slicer::<8, _>(page, bufs, &mut slicer, row_lo, row_hi, target_tag)
 }
 
-struct ByteArrayDecimalColumnSink<'a, const N: usize, T: DataPageSlicer> {
-    slicer: &'a mut T,
-    buffers: &'a mut ColumnChunkBuffers,
-    null_value: [u8; N],
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int32_decimal_dict(
+    page: &DataPage,
+    dict_page: &DictPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    row_lo: usize,
+    row_hi: usize,
+    row_count: usize,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let dict_decoder = RuntimeFixedDictDecoder::try_new(dict_page, 4)?;
+    let error_value = [0u8; 4];
+    let mut slicer = RleDictionarySlicer::try_new(
+        values_buffer,
+        dict_decoder,
+        row_hi,
+        row_count,
+        error_value.as_slice(),
+    )?;
+    decode_int_decimal_with_slicer::<4, _>(page, bufs, &mut slicer, row_lo, row_hi, target_tag)
 }
 
-impl<const N: usize, T: DataPageSlicer> Pushable for ByteArrayDecimalColumnSink<'_, N, T> {
-    fn reserve(&mut self, count: usize) -> ParquetResult<()> {
-        self.buffers.data_vec.reserve(count * N)?;
-        Ok(())
-    }
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int64_decimal_dict(
+    page: &DataPage,
+    dict_page: &DictPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    row_lo: usize,
+    row_hi: usize,
+    row_count: usize,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let dict_decoder = RuntimeFixedDictDecoder::try_new(dict_page, 8)?;
+    let error_value = [0u8; 8];
+    let mut slicer = RleDictionarySlicer::try_new(
+        values_buffer,
+        dict_decoder,
+        row_hi,
+        row_count,
+        error_value.as_slice(),
+    )?;
+    decode_int_decimal_with_slicer::<8, _>(page, bufs, &mut slicer, row_lo, row_hi, target_tag)
+}
 
-    #[inline]
-    fn push(&mut self) -> ParquetResult<()> {
-        let src = self.slicer.next();
-        let base = self.buffers.data_vec.len();
-        debug_assert!(base + N <= self.buffers.data_vec.capacity());
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int32_decimal_filtered<const FILL_NULLS: bool>(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, 4);
+    decode_int_decimal_filtered_with_slicer::<4, FILL_NULLS, _>(
+        page,
+        bufs,
+        &mut slicer,
+        page_row_start,
+        page_row_count,
+        row_group_lo,
+        row_lo,
+        row_hi,
+        rows_filter,
+        target_tag,
+    )
+}
 
-        unsafe {
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int64_decimal_filtered<const FILL_NULLS: bool>(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, 8);
+    decode_int_decimal_filtered_with_slicer::<8, FILL_NULLS, _>(
+        page,
+        bufs,
+        &mut slicer,
+        page_row_start,
+        page_row_count,
+        row_group_lo,
+        row_lo,
+        row_hi,
+        rows_filter,
+        target_tag,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int32_decimal_filtered_dict<const FILL_NULLS: bool>(
+    page: &DataPage,
+    dict_page: &DictPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let dict_decoder = RuntimeFixedDictDecoder::try_new(dict_page, 4)?;
+    let error_value = [0u8; 4];
+    let mut slicer = RleDictionarySlicer::try_new(
+        values_buffer,
+        dict_decoder,
+        page_row_count,
+        page_row_count,
+        error_value.as_slice(),
+    )?;
+    decode_int_decimal_filtered_with_slicer::<4, FILL_NULLS, _>(
+        page,
+        bufs,
+        &mut slicer,
+        page_row_start,
+        page_row_count,
+        row_group_lo,
+        row_lo,
+        row_hi,
+        rows_filter,
+        target_tag,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int64_decimal_filtered_dict<const FILL_NULLS: bool>(
+    page: &DataPage,
+    dict_page: &DictPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let dict_decoder = RuntimeFixedDictDecoder::try_new(dict_page, 8)?;
+    let error_value = [0u8; 8];
+    let mut slicer = RleDictionarySlicer::try_new(
+        values_buffer,
+        dict_decoder,
+        page_row_count,
+        page_row_count,
+        error_value.as_slice(),
+    )?;
+    decode_int_decimal_filtered_with_slicer::<8, FILL_NULLS, _>(
+        page,
+        bufs,
+        &mut slicer,
+        page_row_start,
+        page_row_count,
+        row_group_lo,
+        row_lo,
+        row_hi,
+        rows_filter,
+        target_tag,
+    )
+}
+
+/// Parquet physical type underlying a DECIMAL logical-type column, as permitted by the spec.
+/// Unlike `Int32`/`Int64`/`ByteArray`, `FixedLenByteArray` cannot be decoded without also knowing
+/// its declared byte width, so that width travels with the variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DecimalPhysicalType {
+    Int32,
+    Int64,
+    ByteArray,
+    FixedLenByteArray(usize),
+}
+
+/// Dispatches decimal decode to the entry point matching the column's actual Parquet physical
+/// type, so callers that only know the physical type (rather than which of `decode_fixed_decimal`
+/// / `decode_byte_array_decimal` / `decode_int32_decimal` / `decode_int64_decimal` applies) have a
+/// single function to call. `INT32`/`INT64` sources are precision-bounded per the Parquet spec and
+/// do not currently carry scale coercion or a configurable overflow policy, matching
+/// `decode_int32_decimal`/`decode_int64_decimal` themselves.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int_decimal(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    row_lo: usize,
+    row_hi: usize,
+    row_count: usize,
+    physical_type: DecimalPhysicalType,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    match physical_type {
+        DecimalPhysicalType::Int32 => {
+            decode_int32_decimal(page, bufs, values_buffer, row_lo, row_hi, row_count, target_tag)
+        }
+        DecimalPhysicalType::Int64 => {
+            decode_int64_decimal(page, bufs, values_buffer, row_lo, row_hi, row_count, target_tag)
+        }
+        DecimalPhysicalType::FixedLenByteArray(src_len) => decode_fixed_decimal(
+            page,
+            bufs,
+            values_buffer,
+            row_lo,
+            row_hi,
+            row_count,
+            src_len,
+            src_scale,
+            target_scale,
+            target_tag,
+            overflow_policy,
+        ),
+        DecimalPhysicalType::ByteArray => decode_byte_array_decimal(
+            page,
+            bufs,
+            values_buffer,
+            row_lo,
+            row_hi,
+            row_count,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            target_tag,
+        ),
+    }
+}
+
+/// Dictionary-encoded counterpart of `decode_int_decimal`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int_decimal_dict(
+    page: &DataPage,
+    dict_page: &DictPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    row_lo: usize,
+    row_hi: usize,
+    row_count: usize,
+    physical_type: DecimalPhysicalType,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    match physical_type {
+        DecimalPhysicalType::Int32 => decode_int32_decimal_dict(
+            page,
+            dict_page,
+            bufs,
+            values_buffer,
+            row_lo,
+            row_hi,
+            row_count,
+            target_tag,
+        ),
+        DecimalPhysicalType::Int64 => decode_int64_decimal_dict(
+            page,
+            dict_page,
+            bufs,
+            values_buffer,
+            row_lo,
+            row_hi,
+            row_count,
+            target_tag,
+        ),
+        DecimalPhysicalType::FixedLenByteArray(src_len) => decode_fixed_decimal_dict(
+            page,
+            dict_page,
+            bufs,
+            values_buffer,
+            row_lo,
+            row_hi,
+            row_count,
+            src_len,
+            src_scale,
+            target_scale,
+            target_tag,
+            overflow_policy,
+        ),
+        DecimalPhysicalType::ByteArray => decode_byte_array_decimal_dict(
+            page,
+            dict_page,
+            bufs,
+            values_buffer,
+            row_lo,
+            row_hi,
+            row_count,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            target_tag,
+        ),
+    }
+}
+
+/// Filtered counterpart of `decode_int_decimal`. `stats_min`/`stats_max`/`predicate` enable
+/// row-group/page pruning via `decimal_stats_may_match`, but only take effect for
+/// `FixedLenByteArray`; INT32/INT64/ByteArray statistics pushdown is not yet implemented.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int_decimal_filtered<const FILL_NULLS: bool>(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    physical_type: DecimalPhysicalType,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    stats_min: Option<&[u8]>,
+    stats_max: Option<&[u8]>,
+    predicate: Option<&DecimalStatsPredicate>,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    match physical_type {
+        DecimalPhysicalType::Int32 => decode_int32_decimal_filtered::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            target_tag,
+        ),
+        DecimalPhysicalType::Int64 => decode_int64_decimal_filtered::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            target_tag,
+        ),
+        DecimalPhysicalType::FixedLenByteArray(src_len) => decode_fixed_decimal_filtered::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            src_len,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            stats_min,
+            stats_max,
+            predicate,
+            target_tag,
+        ),
+        DecimalPhysicalType::ByteArray => decode_byte_array_decimal_filtered::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            target_tag,
+        ),
+    }
+}
+
+/// Dictionary-encoded, filtered counterpart of `decode_int_decimal`. See
+/// `decode_int_decimal_filtered` for the scope of `stats_min`/`stats_max`/`predicate`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_int_decimal_filtered_dict<const FILL_NULLS: bool>(
+    page: &DataPage,
+    dict_page: &DictPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    physical_type: DecimalPhysicalType,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    stats_min: Option<&[u8]>,
+    stats_max: Option<&[u8]>,
+    predicate: Option<&DecimalStatsPredicate>,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    match physical_type {
+        DecimalPhysicalType::Int32 => decode_int32_decimal_filtered_dict::<FILL_NULLS>(
+            page,
+            dict_page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            target_tag,
+        ),
+        DecimalPhysicalType::Int64 => decode_int64_decimal_filtered_dict::<FILL_NULLS>(
+            page,
+            dict_page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            target_tag,
+        ),
+        DecimalPhysicalType::FixedLenByteArray(src_len) => {
+            decode_fixed_decimal_filtered_dict::<FILL_NULLS>(
+                page,
+                dict_page,
+                bufs,
+                values_buffer,
+                page_row_start,
+                page_row_count,
+                row_group_lo,
+                row_lo,
+                row_hi,
+                rows_filter,
+                src_len,
+                src_scale,
+                target_scale,
+                overflow_policy,
+                stats_min,
+                stats_max,
+                predicate,
+                target_tag,
+            )
+        }
+        DecimalPhysicalType::ByteArray => decode_byte_array_decimal_filtered_dict::<FILL_NULLS>(
+            page,
+            dict_page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            target_tag,
+        ),
+    }
+}
+
+fn decode_int_decimal_with_slicer<const SRC: usize, T: DataPageSlicer>(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    slicer: &mut T,
+    row_lo: usize,
+    row_hi: usize,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    macro_rules! dispatch {
+        ($target_size:literal, $null_value:expr) => {
+            if $target_size < SRC {
+                Err(fmt_err!(
+                    Unsupported,
+                    "INT{}-backed decimal cannot be decoded to a {}-byte target: would lose significant bits",
+                    SRC * 8,
+                    $target_size
+                ))
+            } else {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut IntDecimalColumnSink::<SRC, $target_size, _>::new(slicer, bufs, $null_value),
+                )
+            }
+        };
+    }
+    match target_tag {
+        ColumnTypeTag::Decimal8 => dispatch!(1, DECIMAL8_NULL),
+        ColumnTypeTag::Decimal16 => dispatch!(2, DECIMAL16_NULL),
+        ColumnTypeTag::Decimal32 => dispatch!(4, DECIMAL32_NULL),
+        ColumnTypeTag::Decimal64 => dispatch!(8, DECIMAL64_NULL),
+        ColumnTypeTag::Decimal128 => dispatch!(16, DECIMAL128_NULL),
+        ColumnTypeTag::Decimal256 => dispatch!(32, DECIMAL256_NULL),
+        _ => Err(fmt_err!(
+            Unsupported,
+            "unsupported target column type {:?} for INT{} decimal",
+            target_tag,
+            SRC * 8
+        )),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn decode_int_decimal_filtered_with_slicer<const SRC: usize, const FILL_NULLS: bool, T: DataPageSlicer>(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    slicer: &mut T,
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    macro_rules! dispatch {
+        ($target_size:literal, $null_value:expr) => {
+            if $target_size < SRC {
+                Err(fmt_err!(
+                    Unsupported,
+                    "INT{}-backed decimal cannot be decoded to a {}-byte target: would lose significant bits",
+                    SRC * 8,
+                    $target_size
+                ))
+            } else {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut IntDecimalColumnSink::<SRC, $target_size, _>::new(slicer, bufs, $null_value),
+                )
+            }
+        };
+    }
+    match target_tag {
+        ColumnTypeTag::Decimal8 => dispatch!(1, DECIMAL8_NULL),
+        ColumnTypeTag::Decimal16 => dispatch!(2, DECIMAL16_NULL),
+        ColumnTypeTag::Decimal32 => dispatch!(4, DECIMAL32_NULL),
+        ColumnTypeTag::Decimal64 => dispatch!(8, DECIMAL64_NULL),
+        ColumnTypeTag::Decimal128 => dispatch!(16, DECIMAL128_NULL),
+        ColumnTypeTag::Decimal256 => dispatch!(32, DECIMAL256_NULL),
+        _ => Err(fmt_err!(
+            Unsupported,
+            "unsupported target column type {:?} for INT{} decimal",
+            target_tag,
+            SRC * 8
+        )),
+    }
+}
+
+/// Sink that reads a fixed-size little-endian two's-complement integer (`SRC` bytes, as
+/// emitted for the Parquet INT32/INT64 physical types) and sign-extends it into the
+/// `N`-byte little-endian QuestDB decimal representation. Unlike `SignExtendDecimalColumnSink`,
+/// the source is already little-endian so no byte reversal is needed, only sign extension.
+struct IntDecimalColumnSink<'a, const SRC: usize, const N: usize, T: DataPageSlicer> {
+    slicer: &'a mut T,
+    buffers: &'a mut ColumnChunkBuffers,
+    null_value: [u8; N],
+}
+
+impl<const SRC: usize, const N: usize, T: DataPageSlicer> Pushable
+    for IntDecimalColumnSink<'_, SRC, N, T>
+{
+    fn reserve(&mut self, count: usize) -> ParquetResult<()> {
+        self.buffers.data_vec.reserve(count * N)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push(&mut self) -> ParquetResult<()> {
+        let src = self.slicer.next();
+        let base = self.buffers.data_vec.len();
+        debug_assert!(base + N <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            Self::convert_decimal(src, ptr);
+            self.buffers.data_vec.set_len(base + N);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_slice(&mut self, count: usize) -> ParquetResult<()> {
+        let base = self.buffers.data_vec.len();
+        let total_bytes = count * N;
+        debug_assert!(base + total_bytes <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            for c in 0..count {
+                let src = self.slicer.next();
+                Self::convert_decimal(src, ptr.add(c * N));
+            }
+            self.buffers.data_vec.set_len(base + total_bytes);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) -> ParquetResult<()> {
+        self.buffers.data_vec.extend_from_slice(&self.null_value)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push_nulls(&mut self, count: usize) -> ParquetResult<()> {
+        let base = self.buffers.data_vec.len();
+        let total_bytes = count * N;
+        debug_assert!(base + total_bytes <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            for i in 0..count {
+                ptr::copy_nonoverlapping(self.null_value.as_ptr(), ptr.add(i * N), N);
+            }
+            self.buffers.data_vec.set_len(base + total_bytes);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) {
+        self.slicer.skip(count);
+    }
+
+    fn result(&self) -> ParquetResult<()> {
+        self.slicer.result()
+    }
+}
+
+impl<'a, const SRC: usize, const N: usize, T: DataPageSlicer> IntDecimalColumnSink<'a, SRC, N, T> {
+    fn new(slicer: &'a mut T, buffers: &'a mut ColumnChunkBuffers, null_value: [u8; N]) -> Self {
+        Self { slicer, buffers, null_value }
+    }
+
+    /// Sign-extends the native little-endian `src` integer into the `N`-byte target. For
+    /// `N <= 8` the target is plain little-endian, so the value is copied in place and the
+    /// remaining high bytes carry the sign. For wider targets (Decimal128/Decimal256),
+    /// QuestDB stores 8-byte words most-significant-first (see `convert_be_decimal_bytes`),
+    /// so the value instead goes in the last word and every word before it is sign-extended.
+    #[inline]
+    unsafe fn convert_decimal(src: &[u8], dest: *mut u8) {
+        debug_assert_eq!(src.len(), SRC);
+        let sign_byte = if src[SRC - 1] & 0x80 != 0 { 0xFF } else { 0x00 };
+        if N <= 8 {
+            ptr::copy_nonoverlapping(src.as_ptr(), dest, SRC);
+            for i in SRC..N {
+                *dest.add(i) = sign_byte;
+            }
+        } else {
+            let low_word_start = N - 8;
+            ptr::copy_nonoverlapping(src.as_ptr(), dest.add(low_word_start), SRC);
+            for i in SRC..8 {
+                *dest.add(low_word_start + i) = sign_byte;
+            }
+            for i in 0..low_word_start {
+                *dest.add(i) = sign_byte;
+            }
+        }
+    }
+}
+
+/// Column sink for a dictionary whose entries are already prematerialized into the target
+/// `N`-byte QuestDB decimal layout (see `PrematerializedFixedDictDecoder`): each row is a
+/// verbatim `N`-byte copy, with no sign-extension or conversion of any kind.
+struct PrematerializedDecimalColumnSink<'a, const N: usize, T: DataPageSlicer> {
+    slicer: &'a mut T,
+    buffers: &'a mut ColumnChunkBuffers,
+    null_value: [u8; N],
+}
+
+impl<const N: usize, T: DataPageSlicer> Pushable for PrematerializedDecimalColumnSink<'_, N, T> {
+    fn reserve(&mut self, count: usize) -> ParquetResult<()> {
+        self.buffers.data_vec.reserve(count * N)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push(&mut self) -> ParquetResult<()> {
+        let src = self.slicer.next();
+        debug_assert_eq!(src.len(), N);
+        self.buffers.data_vec.extend_from_slice(src)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push_slice(&mut self, count: usize) -> ParquetResult<()> {
+        let base = self.buffers.data_vec.len();
+        let total_bytes = count * N;
+        debug_assert!(base + total_bytes <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            for c in 0..count {
+                let src = self.slicer.next();
+                debug_assert_eq!(src.len(), N);
+                ptr::copy_nonoverlapping(src.as_ptr(), ptr.add(c * N), N);
+            }
+            self.buffers.data_vec.set_len(base + total_bytes);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) -> ParquetResult<()> {
+        self.buffers.data_vec.extend_from_slice(&self.null_value)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push_nulls(&mut self, count: usize) -> ParquetResult<()> {
+        let base = self.buffers.data_vec.len();
+        let total_bytes = count * N;
+        debug_assert!(base + total_bytes <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            for i in 0..count {
+                ptr::copy_nonoverlapping(self.null_value.as_ptr(), ptr.add(i * N), N);
+            }
+            self.buffers.data_vec.set_len(base + total_bytes);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) {
+        self.slicer.skip(count);
+    }
+
+    fn result(&self) -> ParquetResult<()> {
+        self.slicer.result()
+    }
+}
+
+impl<'a, const N: usize, T: DataPageSlicer> PrematerializedDecimalColumnSink<'a, N, T> {
+    fn new(slicer: &'a mut T, buffers: &'a mut ColumnChunkBuffers, null_value: [u8; N]) -> Self {
+        Self { slicer, buffers, null_value }
+    }
+}
+
+/// How decimal decode should handle a decoded value that does not fit the target column
+/// width, mirroring arrow-rs's relaxed reader options instead of always aborting the import.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DecimalOverflowPolicy {
+    /// Fail the whole column decode (the historical, strict behavior).
+    Error,
+    /// Write the target's null sentinel for the offending row.
+    Null,
+    /// Clamp to the minimum or maximum value representable in the target width.
+    Saturate,
+}
+
+/// Converts a minimal big-endian two's-complement `src` (as stored for Parquet ByteArray or
+/// FixedLenByteArray decimals) into the `N`-byte little-endian QuestDB decimal representation
+/// at `dest`. Shared by `ByteArrayDecimalColumnSink::convert_decimal` and fixed-decimal
+/// dictionary pre-materialization. On success returns `Ok(())`; if `src` does not fit in `N`
+/// bytes, returns `Err(negative)` with the sign of the source so the caller can decide how to
+/// handle the overflow (hard error, null, or saturate).
+#[inline]
+unsafe fn convert_be_decimal_bytes<const N: usize>(src: &[u8], dest: *mut u8) -> Result<(), bool> {
+    let mut src = src;
+    let mut src_len = src.len();
+    if src_len > N {
+        let sign_byte = if src[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+        let negative = sign_byte == 0xFF;
+        let trunc = src_len - N;
+        if src[..trunc].iter().any(|b| *b != sign_byte) {
+            return Err(negative);
+        }
+        let msb = src[trunc];
+        if (msb & 0x80) != (sign_byte & 0x80) {
+            return Err(negative);
+        }
+        src = &src[trunc..];
+        src_len = N;
+    }
+
+    let sign_byte = if src[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+    if N <= 8 {
+        for i in 0..src_len {
+            *dest.add(i) = src[src_len - 1 - i];
+        }
+        for i in src_len..N {
+            *dest.add(i) = sign_byte;
+        }
+    } else {
+        let words = N / 8;
+        let sign_prefix = N - src_len;
+        for w in 0..words {
+            let word_dest = dest.add(w * 8);
+            for i in 0..8 {
+                let extended_pos = w * 8 + 7 - i;
+                let byte = if extended_pos < sign_prefix {
+                    sign_byte
+                } else {
+                    src[extended_pos - sign_prefix]
+                };
+                *word_dest.add(i) = byte;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Byte index holding the two's-complement sign bit of an `n`-byte QuestDB decimal element:
+/// the last byte for `n <= 8` (plain little-endian), or the last byte of the first 8-byte
+/// word for wider elements, since Decimal128/Decimal256 store words most-significant-first
+/// (see `convert_be_decimal_bytes`).
+#[inline]
+fn decimal_sign_byte_index(n: usize) -> usize {
+    if n <= 8 {
+        n - 1
+    } else {
+        7
+    }
+}
+
+/// Writes the minimum (`negative`) or maximum (`len` bytes, QuestDB on-disk layout)
+/// representable value to `dest`, used by `DecimalOverflowPolicy::Saturate`.
+#[inline]
+unsafe fn write_saturated_bytes(dest: *mut u8, len: usize, negative: bool) {
+    let sign_idx = decimal_sign_byte_index(len);
+    if negative {
+        ptr::write_bytes(dest, 0x00, len);
+        *dest.add(sign_idx) = 0x80;
+    } else {
+        ptr::write_bytes(dest, 0xFF, len);
+        *dest.add(sign_idx) = 0x7F;
+    }
+}
+
+fn decimal_target_size(target_tag: ColumnTypeTag) -> ParquetResult<usize> {
+    match target_tag {
+        ColumnTypeTag::Decimal8 => Ok(1),
+        ColumnTypeTag::Decimal16 => Ok(2),
+        ColumnTypeTag::Decimal32 => Ok(4),
+        ColumnTypeTag::Decimal64 => Ok(8),
+        ColumnTypeTag::Decimal128 => Ok(16),
+        ColumnTypeTag::Decimal256 => Ok(32),
+        _ => Err(fmt_err!(
+            Unsupported,
+            "unsupported target column type {:?} for decimal",
+            target_tag
+        )),
+    }
+}
+
+fn decimal_null_bytes(target_tag: ColumnTypeTag) -> &'static [u8] {
+    match target_tag {
+        ColumnTypeTag::Decimal8 => &DECIMAL8_NULL,
+        ColumnTypeTag::Decimal16 => &DECIMAL16_NULL,
+        ColumnTypeTag::Decimal32 => &DECIMAL32_NULL,
+        ColumnTypeTag::Decimal64 => &DECIMAL64_NULL,
+        ColumnTypeTag::Decimal128 => &DECIMAL128_NULL,
+        ColumnTypeTag::Decimal256 => &DECIMAL256_NULL,
+        _ => &DECIMAL8_NULL,
+    }
+}
+
+/// An inclusive bound used to prune whole row groups or data pages via Parquet `Statistics`
+/// before `decode_fixed_decimal_filtered_with_slicer` decodes a single row. `lo`/`hi` are
+/// minimal big-endian two's-complement integers already expressed in the destination
+/// column's `target_scale`, the same form a caller would use to push down a `WHERE col
+/// BETWEEN lo AND hi` (or a single equality, with `lo == hi`) over the decimal column.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DecimalStatsPredicate<'a> {
+    pub lo: &'a [u8],
+    pub hi: &'a [u8],
+}
+
+/// Decodes a minimal big-endian two's-complement decimal of up to 32 bytes into a 256-bit
+/// little-endian two's-complement buffer, the same conversion `convert_be_decimal_bytes`
+/// applies to ordinary values. Returns `None` if `raw` is empty or wider than 32 bytes,
+/// which cannot legitimately occur for a stored decimal and is treated as "can't decode"
+/// by callers.
+fn decode_be_decimal_to_256(raw: &[u8]) -> Option<[u8; 32]> {
+    if raw.is_empty() || raw.len() > 32 {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    unsafe {
+        convert_be_decimal_bytes::<32>(raw, out.as_mut_ptr()).ok()?;
+    }
+    Some(out)
+}
+
+/// Signed comparison of two `N`-byte QuestDB on-disk decimals (plain little-endian for
+/// `N <= 8`; for wider elements, 8-byte words most-significant-first, each word itself
+/// little-endian - see `convert_be_decimal_bytes`). Compares the most significant word
+/// first, and within each word from its high byte down, so the result matches ordinary
+/// signed comparison regardless of width.
+fn compare_decimal_le<const N: usize>(a: &[u8; N], b: &[u8; N]) -> std::cmp::Ordering {
+    let sign_idx = decimal_sign_byte_index(N);
+    let a_neg = a[sign_idx] & 0x80 != 0;
+    let b_neg = b[sign_idx] & 0x80 != 0;
+    if a_neg != b_neg {
+        return if a_neg {
+            std::cmp::Ordering::Less
+        } else {
+            std::cmp::Ordering::Greater
+        };
+    }
+    if N <= 8 {
+        for i in (0..N).rev() {
+            match a[i].cmp(&b[i]) {
+                std::cmp::Ordering::Equal => {}
+                ord => return ord,
+            }
+        }
+    } else {
+        for word_start in (0..N).step_by(8) {
+            for i in (0..8).rev() {
+                let idx = word_start + i;
+                match a[idx].cmp(&b[idx]) {
+                    std::cmp::Ordering::Equal => {}
+                    ord => return ord,
+                }
+            }
+        }
+    }
+    std::cmp::Ordering::Equal
+}
+
+/// Tests whether a row group's or data page's Parquet `Statistics` min/max (big-endian
+/// two's-complement, in `src_scale`) can possibly contain a value matching `predicate`.
+/// Both bounds are decoded into 256-bit words the same way ordinary values are decoded,
+/// rescaled from `src_scale` to `target_scale` with `rescale_decimal_in_place` so they are
+/// directly comparable to `predicate`'s already-`target_scale` bounds, then compared with
+/// signed 256-bit arithmetic. Returns `true` (keep decoding, don't prune) whenever there is
+/// no predicate, no statistics, or the statistics/rescale cannot be decoded - pruning is
+/// purely a performance optimization and must never reject a page that might contain a
+/// real match.
+fn decimal_stats_may_match(
+    stats_min: Option<&[u8]>,
+    stats_max: Option<&[u8]>,
+    src_scale: i32,
+    target_scale: i32,
+    predicate: Option<&DecimalStatsPredicate>,
+) -> bool {
+    let Some(predicate) = predicate else {
+        return true;
+    };
+    let (Some(stats_min), Some(stats_max)) = (stats_min, stats_max) else {
+        return true;
+    };
+    let (Some(mut min), Some(mut max)) =
+        (decode_be_decimal_to_256(stats_min), decode_be_decimal_to_256(stats_max))
+    else {
+        return true;
+    };
+    let (Some(lo), Some(hi)) =
+        (decode_be_decimal_to_256(predicate.lo), decode_be_decimal_to_256(predicate.hi))
+    else {
+        return true;
+    };
+    if rescale_decimal_in_place(&mut min, &[0u8; 32], src_scale, target_scale).is_err()
+        || rescale_decimal_in_place(&mut max, &[0u8; 32], src_scale, target_scale).is_err()
+    {
+        return true;
+    }
+
+    compare_decimal_le::<32>(&min, &hi) != std::cmp::Ordering::Greater
+        && compare_decimal_le::<32>(&max, &lo) != std::cmp::Ordering::Less
+}
+
+/// Rescale every `target_size`-byte decimal element appended to `bufs.data_vec` since
+/// `rows_before` from `src_scale` to `target_scale`, leaving null sentinels untouched and
+/// applying `overflow_policy` to any row whose rescaled value no longer fits.
+fn rescale_new_rows(
+    bufs: &mut ColumnChunkBuffers,
+    rows_before: usize,
+    target_size: usize,
+    src_scale: i32,
+    target_scale: i32,
+    null_value: &[u8],
+    overflow_policy: DecimalOverflowPolicy,
+) -> ParquetResult<()> {
+    let len = bufs.data_vec.len();
+    let ptr = bufs.data_vec.as_mut_ptr();
+    let mut offset = rows_before;
+    while offset < len {
+        let row = unsafe { std::slice::from_raw_parts_mut(ptr.add(offset), target_size) };
+        if let Err(err) = rescale_decimal_in_place(row, null_value, src_scale, target_scale) {
+            match overflow_policy {
+                DecimalOverflowPolicy::Error => return Err(err),
+                DecimalOverflowPolicy::Null => row.copy_from_slice(null_value),
+                DecimalOverflowPolicy::Saturate => {
+                    let negative = row[decimal_sign_byte_index(target_size)] & 0x80 != 0;
+                    unsafe { write_saturated_bytes(row.as_mut_ptr(), target_size, negative) };
+                }
+            }
+        }
+        offset += target_size;
+    }
+    Ok(())
+}
+
+/// Rescale a single little-endian two's-complement decimal element in place so that its
+/// unscaled integer matches `target_scale` instead of `src_scale`. Widening multiplies by
+/// `10^(target_scale - src_scale)`; narrowing divides by `10^(src_scale - target_scale)`
+/// with round-half-**even** (banker's rounding), erroring if the multiply overflows the
+/// element's width. The null sentinel is passed through untouched.
+///
+/// This is the one rescale algorithm shared by every decimal decode path (plain, dictionary,
+/// filtered, and `ScaleAdjustDecimalColumnSink`), so it deliberately standardizes on
+/// round-half-even rather than round-half-away-from-zero: picking one mode keeps rescaling
+/// consistent across entry points instead of giving otherwise-identical columns different
+/// rounded values depending on which decode path produced them.
+fn rescale_decimal_in_place(
+    buf: &mut [u8],
+    null_value: &[u8],
+    src_scale: i32,
+    target_scale: i32,
+) -> ParquetResult<()> {
+    if src_scale == target_scale || buf == null_value {
+        return Ok(());
+    }
+
+    let (neg, mut limbs) = decimal_bytes_to_magnitude(buf);
+    let delta = target_scale - src_scale;
+    if delta > 0 {
+        for _ in 0..delta {
+            limb_mul_small(&mut limbs, 10);
+        }
+    } else {
+        let k = (-delta) as u32;
+        let mut dropped_digits = Vec::with_capacity(k as usize);
+        for _ in 0..k {
+            dropped_digits.push(limb_div_small(&mut limbs, 10) as u8);
+            limb_trim(&mut limbs);
+        }
+        let most_significant_dropped = *dropped_digits.last().unwrap();
+        let round_up = match most_significant_dropped.cmp(&5) {
+            std::cmp::Ordering::Greater => true,
+            std::cmp::Ordering::Less => false,
+            std::cmp::Ordering::Equal => {
+                let rest_nonzero = dropped_digits[..dropped_digits.len() - 1]
+                    .iter()
+                    .any(|&d| d != 0);
+                rest_nonzero || (limbs[0] & 1) == 1
+            }
+        };
+        if round_up {
+            limb_add_one(&mut limbs);
+        }
+    }
+
+    magnitude_to_decimal_bytes(neg, &limbs, buf)
+}
+
+/// Reverses the 8-byte-word order of a decimal buffer in place. QuestDB stores
+/// Decimal128/Decimal256 columns as 8-byte words with the most significant word first (see
+/// `convert_be_decimal_bytes`), whereas the limb arithmetic below works on a single flat
+/// little-endian integer; this converts between the two, and is its own inverse. A no-op for
+/// 8 bytes or fewer, where both representations coincide.
+fn swap_decimal_word_order(bytes: &mut [u8]) {
+    let words = bytes.len() / 8;
+    for w in 0..words / 2 {
+        let (lo, hi) = (w * 8, (words - 1 - w) * 8);
+        for i in 0..8 {
+            bytes.swap(lo + i, hi + i);
+        }
+    }
+}
+
+/// Decompose a little-endian two's-complement buffer of any width (1-32 bytes) into a sign
+/// flag and its magnitude as little-endian 64-bit limbs. `buf` is in QuestDB's on-disk
+/// layout, so widths above 8 bytes are un-swapped into flat little-endian order first.
+fn decimal_bytes_to_magnitude(buf: &[u8]) -> (bool, Vec<u64>) {
+    let mut bytes = buf.to_vec();
+    swap_decimal_word_order(&mut bytes);
+    let neg = bytes[bytes.len() - 1] & 0x80 != 0;
+    if neg {
+        let mut carry = 1u16;
+        for b in bytes.iter_mut() {
+            let v = u16::from(!*b) + carry;
+            *b = v as u8;
+            carry = v >> 8;
+        }
+    }
+    let mut limbs = Vec::with_capacity(bytes.len().div_ceil(8));
+    for chunk in bytes.chunks(8) {
+        let mut limb_bytes = [0u8; 8];
+        limb_bytes[..chunk.len()].copy_from_slice(chunk);
+        limbs.push(u64::from_le_bytes(limb_bytes));
+    }
+    limb_trim(&mut limbs);
+    (neg, limbs)
+}
+
+/// Re-encode a sign and magnitude (little-endian limbs) as a little-endian two's-complement
+/// buffer, failing if the magnitude does not fit in `buf`'s width. The result is swapped back
+/// into QuestDB's on-disk word order before being written to `buf` (see
+/// `decimal_bytes_to_magnitude`).
+fn magnitude_to_decimal_bytes(neg: bool, limbs: &[u64], buf: &mut [u8]) -> ParquetResult<()> {
+    let n = buf.len();
+    let mut magnitude = vec![0u8; limbs.len() * 8];
+    for (i, limb) in limbs.iter().enumerate() {
+        magnitude[i * 8..i * 8 + 8].copy_from_slice(&limb.to_le_bytes());
+    }
+    if magnitude.len() > n && magnitude[n..].iter().any(|&b| b != 0) {
+        return Err(fmt_err!(
+            Overflow,
+            "decimal rescale overflow: value does not fit in {} bytes",
+            n
+        ));
+    }
+
+    let mut result = vec![0u8; n];
+    let copy_len = magnitude.len().min(n);
+    result[..copy_len].copy_from_slice(&magnitude[..copy_len]);
+
+    let top_bit_set = result[n - 1] & 0x80 != 0;
+    if neg {
+        let is_exact_min = result[n - 1] == 0x80 && result[..n - 1].iter().all(|&b| b == 0);
+        if top_bit_set && !is_exact_min {
+            return Err(fmt_err!(
+                Overflow,
+                "decimal rescale overflow: value does not fit in {} bytes",
+                n
+            ));
+        }
+        let mut carry = 1u16;
+        for b in result.iter_mut() {
+            let v = u16::from(!*b) + carry;
+            *b = v as u8;
+            carry = v >> 8;
+        }
+    } else if top_bit_set {
+        return Err(fmt_err!(
+            Overflow,
+            "decimal rescale overflow: value does not fit in {} bytes",
+            n
+        ));
+    }
+
+    swap_decimal_word_order(&mut result);
+    buf.copy_from_slice(&result);
+    Ok(())
+}
+
+fn limb_trim(limbs: &mut Vec<u64>) {
+    while limbs.len() > 1 && *limbs.last().unwrap() == 0 {
+        limbs.pop();
+    }
+}
+
+fn limb_mul_small(limbs: &mut Vec<u64>, m: u64) {
+    let mut carry: u128 = 0;
+    for limb in limbs.iter_mut() {
+        let prod = u128::from(*limb) * u128::from(m) + carry;
+        *limb = prod as u64;
+        carry = prod >> 64;
+    }
+    while carry > 0 {
+        limbs.push(carry as u64);
+        carry >>= 64;
+    }
+}
+
+fn limb_div_small(limbs: &mut [u64], d: u64) -> u64 {
+    let mut rem: u128 = 0;
+    for limb in limbs.iter_mut().rev() {
+        let cur = (rem << 64) | u128::from(*limb);
+        *limb = (cur / u128::from(d)) as u64;
+        rem = cur % u128::from(d);
+    }
+    rem as u64
+}
+
+fn limb_add_one(limbs: &mut Vec<u64>) {
+    let mut carry = 1u64;
+    for limb in limbs.iter_mut() {
+        let (v, c) = limb.overflowing_add(carry);
+        *limb = v;
+        carry = u64::from(c);
+        if carry == 0 {
+            break;
+        }
+    }
+    if carry > 0 {
+        limbs.push(carry);
+    }
+}
+
+const DECIMAL_DICT_ERROR_VALUE: [u8; 1] = [0u8];
+
+fn decode_byte_array_decimal_with_slicer<T: DataPageSlicer>(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    slicer: &mut T,
+    row_lo: usize,
+    row_hi: usize,
+    overflow_policy: DecimalOverflowPolicy,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    match target_tag {
+        ColumnTypeTag::Decimal8 => decode_page0(
+            page,
+            row_lo,
+            row_hi,
+            &mut ByteArrayDecimalColumnSink::<1, _>::new(slicer, bufs, DECIMAL8_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal16 => decode_page0(
+            page,
+            row_lo,
+            row_hi,
+            &mut ByteArrayDecimalColumnSink::<2, _>::new(slicer, bufs, DECIMAL16_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal32 => decode_page0(
+            page,
+            row_lo,
+            row_hi,
+            &mut ByteArrayDecimalColumnSink::<4, _>::new(slicer, bufs, DECIMAL32_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal64 => decode_page0(
+            page,
+            row_lo,
+            row_hi,
+            &mut ByteArrayDecimalColumnSink::<8, _>::new(slicer, bufs, DECIMAL64_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal128 => decode_page0(
+            page,
+            row_lo,
+            row_hi,
+            &mut ByteArrayDecimalColumnSink::<16, _>::new(slicer, bufs, DECIMAL128_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal256 => decode_page0(
+            page,
+            row_lo,
+            row_hi,
+            &mut ByteArrayDecimalColumnSink::<32, _>::new(slicer, bufs, DECIMAL256_NULL, overflow_policy),
+        ),
+        _ => Err(fmt_err!(
+            Unsupported,
+            "unsupported target column type {:?} for ByteArray decimal",
+            target_tag
+        )),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn decode_byte_array_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPageSlicer>(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    slicer: &mut T,
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    overflow_policy: DecimalOverflowPolicy,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    match target_tag {
+        ColumnTypeTag::Decimal8 => decode_page0_filtered::<_, FILL_NULLS>(
+            page,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            &mut ByteArrayDecimalColumnSink::<1, _>::new(slicer, bufs, DECIMAL8_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal16 => decode_page0_filtered::<_, FILL_NULLS>(
+            page,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            &mut ByteArrayDecimalColumnSink::<2, _>::new(slicer, bufs, DECIMAL16_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal32 => decode_page0_filtered::<_, FILL_NULLS>(
+            page,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            &mut ByteArrayDecimalColumnSink::<4, _>::new(slicer, bufs, DECIMAL32_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal64 => decode_page0_filtered::<_, FILL_NULLS>(
+            page,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            &mut ByteArrayDecimalColumnSink::<8, _>::new(slicer, bufs, DECIMAL64_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal128 => decode_page0_filtered::<_, FILL_NULLS>(
+            page,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            &mut ByteArrayDecimalColumnSink::<16, _>::new(slicer, bufs, DECIMAL128_NULL, overflow_policy),
+        ),
+        ColumnTypeTag::Decimal256 => decode_page0_filtered::<_, FILL_NULLS>(
+            page,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            &mut ByteArrayDecimalColumnSink::<32, _>::new(slicer, bufs, DECIMAL256_NULL, overflow_policy),
+        ),
+        _ => Err(fmt_err!(
+            Unsupported,
+            "unsupported target column type {:?} for ByteArray decimal",
+            target_tag
+        )),
+    }
+}
+
+struct ByteArrayDecimalColumnSink<'a, const N: usize, T: DataPageSlicer> {
+    slicer: &'a mut T,
+    buffers: &'a mut ColumnChunkBuffers,
+    null_value: [u8; N],
+    overflow_policy: DecimalOverflowPolicy,
+}
+
+impl<const N: usize, T: DataPageSlicer> Pushable for ByteArrayDecimalColumnSink<'_, N, T> {
+    fn reserve(&mut self, count: usize) -> ParquetResult<()> {
+        self.buffers.data_vec.reserve(count * N)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push(&mut self) -> ParquetResult<()> {
+        let src = self.slicer.next();
+        let base = self.buffers.data_vec.len();
+        debug_assert!(base + N <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            self.convert_decimal(src, ptr)?;
+            self.buffers.data_vec.set_len(base + N);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_slice(&mut self, count: usize) -> ParquetResult<()> {
+        let base = self.buffers.data_vec.len();
+        let total_bytes = count * N;
+        debug_assert!(base + total_bytes <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            for c in 0..count {
+                let src = self.slicer.next();
+                self.convert_decimal(src, ptr.add(c * N))?;
+            }
+            self.buffers.data_vec.set_len(base + total_bytes);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) -> ParquetResult<()> {
+        self.buffers.data_vec.extend_from_slice(&self.null_value)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push_nulls(&mut self, count: usize) -> ParquetResult<()> {
+        let base = self.buffers.data_vec.len();
+        let total_bytes = count * N;
+        debug_assert!(base + total_bytes <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            for i in 0..count {
+                ptr::copy_nonoverlapping(self.null_value.as_ptr(), ptr.add(i * N), N);
+            }
+            self.buffers.data_vec.set_len(base + total_bytes);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) {
+        self.slicer.skip(count);
+    }
+
+    fn result(&self) -> ParquetResult<()> {
+        self.slicer.result()
+    }
+}
+
+impl<'a, const N: usize, T: DataPageSlicer> ByteArrayDecimalColumnSink<'a, N, T> {
+    fn new(
+        slicer: &'a mut T,
+        buffers: &'a mut ColumnChunkBuffers,
+        null_value: [u8; N],
+        overflow_policy: DecimalOverflowPolicy,
+    ) -> Self {
+        Self { slicer, buffers, null_value, overflow_policy }
+    }
+
+    /// Writes a decoded source value that does not fit `N` bytes according to
+    /// `self.overflow_policy`: the target's null sentinel, the min/max representable
+    /// value for the sign of the source (`negative`), or an `Unsupported` error.
+    #[inline]
+    unsafe fn handle_overflow(&self, dest: *mut u8, negative: bool, reason: &str) -> ParquetResult<()> {
+        match self.overflow_policy {
+            DecimalOverflowPolicy::Error => Err(fmt_err!(
+                Unsupported,
+                "ByteArray decimal cannot be decoded to target size {} bytes: {}",
+                N,
+                reason
+            )),
+            DecimalOverflowPolicy::Null => {
+                ptr::copy_nonoverlapping(self.null_value.as_ptr(), dest, N);
+                Ok(())
+            }
+            DecimalOverflowPolicy::Saturate => {
+                write_saturated_bytes(dest, N, negative);
+                Ok(())
+            }
+        }
+    }
+
+    #[inline]
+    unsafe fn convert_decimal(&self, src: &[u8], dest: *mut u8) -> ParquetResult<()> {
+        if src.is_empty() {
+            return Err(fmt_err!(
+                Unsupported,
+                "invalid ByteArray decimal source length 0 for target size {}",
+                N
+            ));
+        }
+        if let Err(negative) = convert_be_decimal_bytes::<N>(src, dest) {
+            return self.handle_overflow(
+                dest,
+                negative,
+                "source is larger than target and would not fit without truncating significant digits",
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Writes `dest` according to `policy` when a decimal conversion step does not fit the target
+/// width: the target's null sentinel, the min/max representable value for `negative`'s sign, or
+/// an `Unsupported` error describing `reason`. Used by `ScaleAdjustDecimalColumnSink`, whose
+/// conversion can overflow either at the initial width conversion or at the rescale step.
+#[inline]
+unsafe fn apply_overflow_policy<const N: usize>(
+    policy: DecimalOverflowPolicy,
+    dest: *mut u8,
+    negative: bool,
+    null_value: &[u8],
+    reason: &str,
+) -> ParquetResult<()> {
+    match policy {
+        DecimalOverflowPolicy::Error => Err(fmt_err!(
+            Unsupported,
+            "decimal value cannot be decoded to target size {} bytes: {}",
+            N,
+            reason
+        )),
+        DecimalOverflowPolicy::Null => {
+            ptr::copy_nonoverlapping(null_value.as_ptr(), dest, N);
+            Ok(())
+        }
+        DecimalOverflowPolicy::Saturate => {
+            write_saturated_bytes(dest, N, negative);
+            Ok(())
+        }
+    }
+}
+
+/// Sink used by the filtered fixed-decimal decode path (`decode_fixed_decimal_filtered_with_slicer`
+/// and the `decode_fixed_decimal_impl!` macro's filtered arms) when the Parquet column's decimal
+/// scale differs from the target column's scale. Converts each big-endian source value into the
+/// `N`-byte little-endian target representation with `convert_be_decimal_bytes`, then rescales
+/// the unscaled integer from `src_scale` to `target_scale` in place via
+/// `rescale_decimal_in_place`. Either step can overflow the target width; both are handled per
+/// `overflow_policy`.
+struct ScaleAdjustDecimalColumnSink<'a, const N: usize, T: DataPageSlicer> {
+    slicer: &'a mut T,
+    buffers: &'a mut ColumnChunkBuffers,
+    null_value: [u8; N],
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+}
+
+impl<const N: usize, T: DataPageSlicer> Pushable for ScaleAdjustDecimalColumnSink<'_, N, T> {
+    fn reserve(&mut self, count: usize) -> ParquetResult<()> {
+        self.buffers.data_vec.reserve(count * N)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push(&mut self) -> ParquetResult<()> {
+        let src = self.slicer.next();
+        let base = self.buffers.data_vec.len();
+        debug_assert!(base + N <= self.buffers.data_vec.capacity());
+
+        unsafe {
             let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
-            Self::convert_decimal(src, ptr)?;
+            self.convert_and_rescale(src, ptr)?;
             self.buffers.data_vec.set_len(base + N);
         }
         Ok(())
@@ -414,7 +2038,7 @@ impl<const N: usize, T: DataPageSlicer> Pushable for ByteArrayDecimalColumnSink<
             let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
             for c in 0..count {
                 let src = self.slicer.next();
-                Self::convert_decimal(src, ptr.add(c * N))?;
+                self.convert_and_rescale(src, ptr.add(c * N))?;
             }
             self.buffers.data_vec.set_len(base + total_bytes);
         }
@@ -453,75 +2077,236 @@ impl<const N: usize, T: DataPageSlicer> Pushable for ByteArrayDecimalColumnSink<
     }
 }
 
-impl<'a, const N: usize, T: DataPageSlicer> ByteArrayDecimalColumnSink<'a, N, T> {
-    fn new(slicer: &'a mut T, buffers: &'a mut ColumnChunkBuffers, null_value: [u8; N]) -> Self {
-        Self { slicer, buffers, null_value }
+impl<'a, const N: usize, T: DataPageSlicer> ScaleAdjustDecimalColumnSink<'a, N, T> {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        slicer: &'a mut T,
+        buffers: &'a mut ColumnChunkBuffers,
+        null_value: [u8; N],
+        src_scale: i32,
+        target_scale: i32,
+        overflow_policy: DecimalOverflowPolicy,
+    ) -> Self {
+        Self {
+            slicer,
+            buffers,
+            null_value,
+            src_scale,
+            target_scale,
+            overflow_policy,
+        }
     }
 
     #[inline]
-    unsafe fn convert_decimal(src: &[u8], dest: *mut u8) -> ParquetResult<()> {
-        let mut src = src;
-        let mut src_len = src.len();
-        if src_len == 0 {
+    unsafe fn convert_and_rescale(&self, src: &[u8], dest: *mut u8) -> ParquetResult<()> {
+        if src.is_empty() {
             return Err(fmt_err!(
                 Unsupported,
-                "invalid ByteArray decimal source length 0 for target size {}",
+                "invalid FixedLenByteArray decimal source length 0 for target size {}",
                 N
             ));
         }
+        if let Err(negative) = convert_be_decimal_bytes::<N>(src, dest) {
+            return apply_overflow_policy::<N>(
+                self.overflow_policy,
+                dest,
+                negative,
+                &self.null_value,
+                "source is larger than target and would not fit without truncating significant digits",
+            );
+        }
+        let row = std::slice::from_raw_parts_mut(dest, N);
+        if rescale_decimal_in_place(row, &self.null_value, self.src_scale, self.target_scale).is_err() {
+            let negative = row[decimal_sign_byte_index(N)] & 0x80 != 0;
+            return apply_overflow_policy::<N>(
+                self.overflow_policy,
+                dest,
+                negative,
+                &self.null_value,
+                "rescaled value does not fit in target size",
+            );
+        }
+        Ok(())
+    }
+}
 
-        if src_len > N {
-            let sign_byte = if src[0] & 0x80 != 0 { 0xFF } else { 0x00 };
-            let trunc = src_len - N;
-            if src[..trunc].iter().any(|b| *b != sign_byte) {
-                return Err(fmt_err!(
-                    Unsupported,
-                    "ByteArray({}) decimal cannot be decoded to target size {} bytes: \
-                     source is larger than target and not sign-extended",
-                    src_len,
-                    N
-                ));
+/// Sink selected when a FixedLenByteArray decimal source is wider than the target column
+/// (`src_len > target_size`), e.g. decoding a 16-byte source into a `Decimal64` column.
+/// `convert_be_decimal_bytes` already checks that the dropped high bytes are all `0x00`/`0xFF`
+/// consistent with the sign of the retained `N` bytes; on failure (the value does not actually
+/// fit), this writes the target's null sentinel when `FILL_NULLS` is set, or fails the decode
+/// with `Unsupported` otherwise — the same flag the filtered decode paths already use to decide
+/// how out-of-range rows are handled.
+struct NarrowingDecimalColumnSink<'a, const N: usize, const FILL_NULLS: bool, T: DataPageSlicer> {
+    slicer: &'a mut T,
+    buffers: &'a mut ColumnChunkBuffers,
+    null_value: [u8; N],
+}
+
+impl<const N: usize, const FILL_NULLS: bool, T: DataPageSlicer> Pushable
+    for NarrowingDecimalColumnSink<'_, N, FILL_NULLS, T>
+{
+    fn reserve(&mut self, count: usize) -> ParquetResult<()> {
+        self.buffers.data_vec.reserve(count * N)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push(&mut self) -> ParquetResult<()> {
+        let src = self.slicer.next();
+        let base = self.buffers.data_vec.len();
+        debug_assert!(base + N <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            self.convert_decimal(src, ptr)?;
+            self.buffers.data_vec.set_len(base + N);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_slice(&mut self, count: usize) -> ParquetResult<()> {
+        let base = self.buffers.data_vec.len();
+        let total_bytes = count * N;
+        debug_assert!(base + total_bytes <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            for c in 0..count {
+                let src = self.slicer.next();
+                self.convert_decimal(src, ptr.add(c * N))?;
             }
-            let msb = src[trunc];
-            if (msb & 0x80) != (sign_byte & 0x80) {
-                return Err(fmt_err!(
+            self.buffers.data_vec.set_len(base + total_bytes);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn push_null(&mut self) -> ParquetResult<()> {
+        self.buffers.data_vec.extend_from_slice(&self.null_value)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push_nulls(&mut self, count: usize) -> ParquetResult<()> {
+        let base = self.buffers.data_vec.len();
+        let total_bytes = count * N;
+        debug_assert!(base + total_bytes <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            for i in 0..count {
+                ptr::copy_nonoverlapping(self.null_value.as_ptr(), ptr.add(i * N), N);
+            }
+            self.buffers.data_vec.set_len(base + total_bytes);
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn skip(&mut self, count: usize) {
+        self.slicer.skip(count);
+    }
+
+    fn result(&self) -> ParquetResult<()> {
+        self.slicer.result()
+    }
+}
+
+impl<'a, const N: usize, const FILL_NULLS: bool, T: DataPageSlicer>
+    NarrowingDecimalColumnSink<'a, N, FILL_NULLS, T>
+{
+    fn new(slicer: &'a mut T, buffers: &'a mut ColumnChunkBuffers, null_value: [u8; N]) -> Self {
+        Self { slicer, buffers, null_value }
+    }
+
+    #[inline]
+    unsafe fn convert_decimal(&self, src: &[u8], dest: *mut u8) -> ParquetResult<()> {
+        if convert_be_decimal_bytes::<N>(src, dest).is_err() {
+            return if FILL_NULLS {
+                ptr::copy_nonoverlapping(self.null_value.as_ptr(), dest, N);
+                Ok(())
+            } else {
+                Err(fmt_err!(
                     Unsupported,
-                    "ByteArray({}) decimal cannot be decoded to target size {} bytes: \
-                     source is larger than target and would truncate significant digits",
-                    src_len,
+                    "FixedLenByteArray({}) decimal does not fit target size {} bytes without truncating significant digits",
+                    src.len(),
                     N
-                ));
-            }
-            src = &src[trunc..];
-            src_len = N;
+                ))
+            };
         }
+        Ok(())
+    }
+}
 
-        let sign_byte = if src[0] & 0x80 != 0 { 0xFF } else { 0x00 };
-        if N <= 8 {
-            for i in 0..src_len {
-                *dest.add(i) = src[src_len - 1 - i];
-            }
-            for i in src_len..N {
-                *dest.add(i) = sign_byte;
-            }
-        } else {
-            let words = N / 8;
-            let sign_prefix = N - src_len;
-            for w in 0..words {
-                let word_dest = dest.add(w * 8);
-                for i in 0..8 {
-                    let extended_pos = w * 8 + 7 - i;
-                    let byte = if extended_pos < sign_prefix {
-                        sign_byte
-                    } else {
-                        src[extended_pos - sign_prefix]
-                    };
-                    *word_dest.add(i) = byte;
-                }
+/// Sink used when Parquet column-chunk/page `Statistics` prove (via `decimal_stats_may_match`)
+/// that a whole page cannot satisfy the active predicate: every row becomes the target's null
+/// sentinel without ever resolving or converting the underlying source bytes, so the per-row
+/// decode cost `ReverseFixedColumnSink`/`SignExtendDecimalColumnSink`/`ScaleAdjustDecimalColumnSink`/
+/// `NarrowingDecimalColumnSink` would otherwise pay is avoided entirely. `push`/`push_slice`
+/// still advance `slicer` via `skip` so row alignment against `rows_filter` stays correct for
+/// later pages.
+struct StatsPrunedDecimalColumnSink<'a, const N: usize, T: DataPageSlicer> {
+    slicer: &'a mut T,
+    buffers: &'a mut ColumnChunkBuffers,
+    null_value: [u8; N],
+}
+
+impl<const N: usize, T: DataPageSlicer> Pushable for StatsPrunedDecimalColumnSink<'_, N, T> {
+    fn reserve(&mut self, count: usize) -> ParquetResult<()> {
+        self.buffers.data_vec.reserve(count * N)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push(&mut self) -> ParquetResult<()> {
+        self.slicer.skip(1);
+        self.push_null()
+    }
+
+    #[inline]
+    fn push_slice(&mut self, count: usize) -> ParquetResult<()> {
+        self.slicer.skip(count);
+        self.push_nulls(count)
+    }
+
+    #[inline]
+    fn push_null(&mut self) -> ParquetResult<()> {
+        self.buffers.data_vec.extend_from_slice(&self.null_value)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn push_nulls(&mut self, count: usize) -> ParquetResult<()> {
+        let base = self.buffers.data_vec.len();
+        let total_bytes = count * N;
+        debug_assert!(base + total_bytes <= self.buffers.data_vec.capacity());
+
+        unsafe {
+            let ptr = self.buffers.data_vec.as_mut_ptr().add(base);
+            for i in 0..count {
+                ptr::copy_nonoverlapping(self.null_value.as_ptr(), ptr.add(i * N), N);
             }
+            self.buffers.data_vec.set_len(base + total_bytes);
         }
         Ok(())
     }
+
+    #[inline]
+    fn skip(&mut self, count: usize) {
+        self.slicer.skip(count);
+    }
+
+    fn result(&self) -> ParquetResult<()> {
+        self.slicer.result()
+    }
+}
+
+impl<'a, const N: usize, T: DataPageSlicer> StatsPrunedDecimalColumnSink<'a, N, T> {
+    fn new(slicer: &'a mut T, buffers: &'a mut ColumnChunkBuffers, null_value: [u8; N]) -> Self {
+        Self { slicer, buffers, null_value }
+    }
 }
 
 struct RuntimeFixedDictDecoder<'a> {
@@ -548,19 +2333,142 @@ impl DictDecoder for RuntimeFixedDictDecoder<'_> {
     }
 }
 
-impl<'a> RuntimeFixedDictDecoder<'a> {
-    fn try_new(dict_page: &'a DictPage, value_size: usize) -> ParquetResult<Self> {
-        if value_size == 0 {
-            return Err(fmt_err!(Layout, "dictionary fixed value size must be > 0"));
-        }
-        if value_size * dict_page.num_values != dict_page.buffer.len() {
-            return Err(fmt_err!(
-                Layout,
-                "dictionary data page size is not multiple of {value_size}"
-            ));
-        }
-        Ok(Self { dict_page: dict_page.buffer.as_ref(), value_size })
+impl<'a> RuntimeFixedDictDecoder<'a> {
+    fn try_new(dict_page: &'a DictPage, value_size: usize) -> ParquetResult<Self> {
+        if value_size == 0 {
+            return Err(fmt_err!(Layout, "dictionary fixed value size must be > 0"));
+        }
+        if value_size * dict_page.num_values != dict_page.buffer.len() {
+            return Err(fmt_err!(
+                Layout,
+                "dictionary data page size is not multiple of {value_size}"
+            ));
+        }
+        Ok(Self { dict_page: dict_page.buffer.as_ref(), value_size })
+    }
+}
+
+/// A `DictDecoder` whose table already holds each distinct dictionary value converted to its
+/// final `N`-byte little-endian QuestDB representation, computed once in `try_new` instead of
+/// on every RLE-expanded row. Low-cardinality decimal columns dominated by repeated dictionary
+/// keys turn per-row conversion cost into per-distinct-value cost.
+struct PrematerializedFixedDictDecoder {
+    table: Vec<u8>,
+    value_size: usize,
+}
+
+impl DictDecoder for PrematerializedFixedDictDecoder {
+    #[inline]
+    fn get_dict_value(&self, index: u32) -> &[u8] {
+        let start = index as usize * self.value_size;
+        let end = start + self.value_size;
+        self.table[start..end].as_ref()
+    }
+
+    #[inline]
+    fn avg_key_len(&self) -> f32 {
+        self.value_size as f32
+    }
+
+    #[inline]
+    fn len(&self) -> u32 {
+        (self.table.len() / self.value_size) as u32
+    }
+}
+
+impl PrematerializedFixedDictDecoder {
+    fn try_new<const N: usize>(dict_page: &DictPage, src_len: usize) -> ParquetResult<Self> {
+        let raw = RuntimeFixedDictDecoder::try_new(dict_page, src_len)?;
+        let count = raw.len() as usize;
+        let mut table = vec![0u8; count * N];
+        for i in 0..count {
+            let src = raw.get_dict_value(i as u32);
+            unsafe {
+                let dest = table.as_mut_ptr().add(i * N);
+                convert_be_decimal_bytes::<N>(src, dest).map_err(|_| {
+                    fmt_err!(
+                        Unsupported,
+                        "dictionary value {} does not fit target size {} bytes",
+                        i,
+                        N
+                    )
+                })?;
+            }
+        }
+        Ok(Self { table, value_size: N })
+    }
+}
+
+/// Fixed-decimal dictionary decode that pre-materializes every distinct dictionary value into
+/// its final target-width representation once (see `PrematerializedFixedDictDecoder`), then
+/// has the sink copy `N` bytes straight from that table per RLE-expanded index — no further
+/// sign-extension or word-swap per row, unlike `decode_fixed_decimal_dict`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_fixed_decimal_dict_prematerialized(
+    page: &DataPage,
+    dict_page: &DictPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    row_lo: usize,
+    row_hi: usize,
+    row_count: usize,
+    src_len: usize,
+    src_scale: i32,
+    target_scale: i32,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let rows_before = bufs.data_vec.len();
+    macro_rules! dispatch {
+        ($target_size:literal, $null_value:expr) => {{
+            let dict_decoder = PrematerializedFixedDictDecoder::try_new::<$target_size>(dict_page, src_len)?;
+            let error_value = [0u8; $target_size];
+            let mut slicer = RleDictionarySlicer::try_new(
+                values_buffer,
+                dict_decoder,
+                row_hi,
+                row_count,
+                error_value.as_slice(),
+            )?;
+            decode_page0(
+                page,
+                row_lo,
+                row_hi,
+                &mut PrematerializedDecimalColumnSink::<$target_size, _>::new(
+                    &mut slicer,
+                    bufs,
+                    $null_value,
+                ),
+            )
+        }};
+    }
+    match target_tag {
+        ColumnTypeTag::Decimal8 => dispatch!(1, DECIMAL8_NULL),
+        ColumnTypeTag::Decimal16 => dispatch!(2, DECIMAL16_NULL),
+        ColumnTypeTag::Decimal32 => dispatch!(4, DECIMAL32_NULL),
+        ColumnTypeTag::Decimal64 => dispatch!(8, DECIMAL64_NULL),
+        ColumnTypeTag::Decimal128 => dispatch!(16, DECIMAL128_NULL),
+        ColumnTypeTag::Decimal256 => dispatch!(32, DECIMAL256_NULL),
+        _ => Err(fmt_err!(
+            Unsupported,
+            "unsupported target column type {:?} for FixedLenByteArray decimal",
+            target_tag
+        )),
+    }?;
+
+    if src_scale != target_scale {
+        // The prematerialized dictionary path takes no overflow_policy parameter, so a rescale
+        // overflow always fails the decode, as a dictionary value that does not fit already does.
+        rescale_new_rows(
+            bufs,
+            rows_before,
+            decimal_target_size(target_tag)?,
+            src_scale,
+            target_scale,
+            decimal_null_bytes(target_tag),
+            DecimalOverflowPolicy::Error,
+        )?;
     }
+    Ok(())
 }
 
 fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
@@ -571,7 +2479,12 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
     row_hi: usize,
     src_len: usize,
     target_tag: ColumnTypeTag,
+    overflow_policy: DecimalOverflowPolicy,
 ) -> ParquetResult<()> {
+    // `NarrowingDecimalColumnSink` only has an Error-vs-null-fill choice, so `Saturate` maps to
+    // the same null-fill branch as `Null` here, matching the capability the filtered decode
+    // paths already expose for this narrowing-without-rescale case.
+    let fill_nulls = overflow_policy != DecimalOverflowPolicy::Error;
     let target_size = match target_tag {
         ColumnTypeTag::Decimal8 => 1,
         ColumnTypeTag::Decimal16 => 2,
@@ -606,7 +2519,7 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                     row_hi,
                     &mut ReverseFixedColumnSink::<1, _>::new(slicer, bufs, DECIMAL8_NULL),
                 )
-            } else {
+            } else if src_len < 1 {
                 decode_page0(
                     page,
                     row_lo,
@@ -618,6 +2531,20 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                         src_len,
                     ),
                 )
+            } else if fill_nulls {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<1, true, _>::new(slicer, bufs, DECIMAL8_NULL),
+                )
+            } else {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<1, false, _>::new(slicer, bufs, DECIMAL8_NULL),
+                )
             }
         }
         ColumnTypeTag::Decimal16 => {
@@ -628,7 +2555,7 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                     row_hi,
                     &mut ReverseFixedColumnSink::<2, _>::new(slicer, bufs, DECIMAL16_NULL),
                 )
-            } else {
+            } else if src_len < 2 {
                 decode_page0(
                     page,
                     row_lo,
@@ -640,6 +2567,20 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                         src_len,
                     ),
                 )
+            } else if fill_nulls {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<2, true, _>::new(slicer, bufs, DECIMAL16_NULL),
+                )
+            } else {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<2, false, _>::new(slicer, bufs, DECIMAL16_NULL),
+                )
             }
         }
         ColumnTypeTag::Decimal32 => {
@@ -650,7 +2591,7 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                     row_hi,
                     &mut ReverseFixedColumnSink::<4, _>::new(slicer, bufs, DECIMAL32_NULL),
                 )
-            } else {
+            } else if src_len < 4 {
                 decode_page0(
                     page,
                     row_lo,
@@ -662,6 +2603,20 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                         src_len,
                     ),
                 )
+            } else if fill_nulls {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<4, true, _>::new(slicer, bufs, DECIMAL32_NULL),
+                )
+            } else {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<4, false, _>::new(slicer, bufs, DECIMAL32_NULL),
+                )
             }
         }
         ColumnTypeTag::Decimal64 => {
@@ -672,7 +2627,7 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                     row_hi,
                     &mut ReverseFixedColumnSink::<8, _>::new(slicer, bufs, DECIMAL64_NULL),
                 )
-            } else {
+            } else if src_len < 8 {
                 decode_page0(
                     page,
                     row_lo,
@@ -684,6 +2639,20 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                         src_len,
                     ),
                 )
+            } else if fill_nulls {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<8, true, _>::new(slicer, bufs, DECIMAL64_NULL),
+                )
+            } else {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<8, false, _>::new(slicer, bufs, DECIMAL64_NULL),
+                )
             }
         }
         ColumnTypeTag::Decimal128 => {
@@ -694,7 +2663,7 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                     row_hi,
                     &mut WordSwapDecimalColumnSink::<16, 2, _>::new(slicer, bufs, DECIMAL128_NULL),
                 )
-            } else {
+            } else if src_len < 16 {
                 decode_page0(
                     page,
                     row_lo,
@@ -706,6 +2675,20 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                         src_len,
                     ),
                 )
+            } else if fill_nulls {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<16, true, _>::new(slicer, bufs, DECIMAL128_NULL),
+                )
+            } else {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<16, false, _>::new(slicer, bufs, DECIMAL128_NULL),
+                )
             }
         }
         ColumnTypeTag::Decimal256 => {
@@ -716,7 +2699,7 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                     row_hi,
                     &mut WordSwapDecimalColumnSink::<32, 4, _>::new(slicer, bufs, DECIMAL256_NULL),
                 )
-            } else {
+            } else if src_len < 32 {
                 decode_page0(
                     page,
                     row_lo,
@@ -728,6 +2711,20 @@ fn decode_fixed_decimal_with_slicer<T: DataPageSlicer>(
                         src_len,
                     ),
                 )
+            } else if fill_nulls {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<32, true, _>::new(slicer, bufs, DECIMAL256_NULL),
+                )
+            } else {
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<32, false, _>::new(slicer, bufs, DECIMAL256_NULL),
+                )
             }
         }
         _ => Err(fmt_err!(
@@ -750,6 +2747,12 @@ pub(crate) fn decode_fixed_decimal_filtered<const FILL_NULLS: bool>(
     row_hi: usize,
     rows_filter: &[i64],
     src_len: usize,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    stats_min: Option<&[u8]>,
+    stats_max: Option<&[u8]>,
+    predicate: Option<&DecimalStatsPredicate>,
     target_tag: ColumnTypeTag,
 ) -> ParquetResult<()> {
     let target_size = match target_tag {
@@ -778,77 +2781,368 @@ pub(crate) fn decode_fixed_decimal_filtered<const FILL_NULLS: bool>(
     }
 
     match target_tag {
-        ColumnTypeTag::Decimal8 => decode_fixed_decimal_filtered_1::<FILL_NULLS>(
+        ColumnTypeTag::Decimal8 => decode_fixed_decimal_filtered_1::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            src_len,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            stats_min,
+            stats_max,
+            predicate,
+        ),
+        ColumnTypeTag::Decimal16 => decode_fixed_decimal_filtered_2::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            src_len,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            stats_min,
+            stats_max,
+            predicate,
+        ),
+        ColumnTypeTag::Decimal32 => decode_fixed_decimal_filtered_4::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            src_len,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            stats_min,
+            stats_max,
+            predicate,
+        ),
+        ColumnTypeTag::Decimal64 => decode_fixed_decimal_filtered_8::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            src_len,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            stats_min,
+            stats_max,
+            predicate,
+        ),
+        ColumnTypeTag::Decimal128 => decode_fixed_decimal_filtered_16::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            src_len,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            stats_min,
+            stats_max,
+            predicate,
+        ),
+        ColumnTypeTag::Decimal256 => decode_fixed_decimal_filtered_32::<FILL_NULLS>(
+            page,
+            bufs,
+            values_buffer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            src_len,
+            src_scale,
+            target_scale,
+            overflow_policy,
+            stats_min,
+            stats_max,
+            predicate,
+        ),
+        _ => Err(fmt_err!(
+            Unsupported,
+            "unsupported target column type {:?} for FixedLenByteArray decimal",
+            target_tag
+        )),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_fixed_decimal_filtered_dict<const FILL_NULLS: bool>(
+    page: &DataPage,
+    dict_page: &DictPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    src_len: usize,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    stats_min: Option<&[u8]>,
+    stats_max: Option<&[u8]>,
+    predicate: Option<&DecimalStatsPredicate>,
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let dict_decoder = RuntimeFixedDictDecoder::try_new(dict_page, src_len)?;
+    let error_value = vec![0u8; src_len];
+    let mut slicer = RleDictionarySlicer::try_new(
+        values_buffer,
+        dict_decoder,
+        page_row_count,
+        page_row_count,
+        error_value.as_slice(),
+    )?;
+    decode_fixed_decimal_filtered_with_slicer::<FILL_NULLS, _>(
+        page,
+        bufs,
+        &mut slicer,
+        page_row_start,
+        page_row_count,
+        row_group_lo,
+        row_lo,
+        row_hi,
+        rows_filter,
+        src_len,
+        src_scale,
+        target_scale,
+        overflow_policy,
+        stats_min,
+        stats_max,
+        predicate,
+        target_tag,
+    )
+}
+
+/// Alternate filtered decode mode selectable alongside `decode_fixed_decimal_filtered`: rather
+/// than branching per row on `rows_filter`, this decodes the whole page's decimal values into
+/// a contiguous staging region of `bufs.data_vec` and then compacts the rows selected by `mask`
+/// (a packed bitmap aligned to the page's rows, one bit per row) down to the front of that same
+/// region, word-at-a-time. This is worth the extra staging space for selective predicates over
+/// large row groups, where the per-row branch of the filtered path mispredicts badly.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_fixed_decimal_bitmap_filtered(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_count: usize,
+    src_len: usize,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    mask: &[u8],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let target_size = decimal_target_size(target_tag)?;
+    let stage_start = bufs.data_vec.len();
+    decode_fixed_decimal(
+        page,
+        bufs,
+        values_buffer,
+        0,
+        page_row_count,
+        page_row_count,
+        src_len,
+        src_scale,
+        target_scale,
+        target_tag,
+        overflow_policy,
+    )?;
+    compact_by_bitmap(bufs, stage_start, page_row_count, target_size, mask);
+    Ok(())
+}
+
+/// Dictionary-encoded counterpart of `decode_fixed_decimal_bitmap_filtered`: decodes every row
+/// of the page (resolving each RLE-expanded dictionary index) into the staging region, then
+/// compacts with the same bitmap kernel.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_fixed_decimal_bitmap_filtered_dict(
+    page: &DataPage,
+    dict_page: &DictPage,
+    bufs: &mut ColumnChunkBuffers,
+    values_buffer: &[u8],
+    page_row_count: usize,
+    src_len: usize,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    mask: &[u8],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    let target_size = decimal_target_size(target_tag)?;
+    let stage_start = bufs.data_vec.len();
+    decode_fixed_decimal_dict(
+        page,
+        dict_page,
+        bufs,
+        values_buffer,
+        0,
+        page_row_count,
+        page_row_count,
+        src_len,
+        src_scale,
+        target_scale,
+        target_tag,
+        overflow_policy,
+    )?;
+    compact_by_bitmap(bufs, stage_start, page_row_count, target_size, mask);
+    Ok(())
+}
+
+/// Compacts the `row_count` fixed-width (`elem_size` bytes) elements staged at
+/// `bufs.data_vec[region_start..]` down to the rows selected by `mask`, a packed bitmap with
+/// one bit per row (bit `i` set means row `i` is kept). Processes the mask 64 bits at a time
+/// and walks only its set bits via `trailing_zeros`/clear-lowest-set-bit, so the loop never
+/// branches on the "skip" case — unset bits cost nothing beyond the popcount of the word.
+fn compact_by_bitmap(
+    bufs: &mut ColumnChunkBuffers,
+    region_start: usize,
+    row_count: usize,
+    elem_size: usize,
+    mask: &[u8],
+) {
+    unsafe {
+        let base_ptr = bufs.data_vec.as_mut_ptr();
+        let mut write_offset = region_start;
+        let mut row_base = 0usize;
+        let mut byte_idx = 0usize;
+        while row_base < row_count {
+            let mut word: u64 = 0;
+            for i in 0..8 {
+                if let Some(&b) = mask.get(byte_idx + i) {
+                    word |= u64::from(b) << (i * 8);
+                }
+            }
+            while word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                let src_row = row_base + bit;
+                if src_row < row_count {
+                    ptr::copy(
+                        base_ptr.add(region_start + src_row * elem_size),
+                        base_ptr.add(write_offset),
+                        elem_size,
+                    );
+                    write_offset += elem_size;
+                }
+                word &= word - 1;
+            }
+            row_base += 64;
+            byte_idx += 8;
+        }
+        bufs.data_vec.set_len(write_offset);
+    }
+}
+
+/// Emits nulls for every row of a page pruned by `decimal_stats_may_match`, still routing
+/// through `decode_page0_filtered` (and thus `slicer`/`rows_filter`) so `page_row_start` and
+/// row alignment against later pages stay correct even though no source byte is decoded.
+#[allow(clippy::too_many_arguments)]
+fn decode_stats_pruned_page<const FILL_NULLS: bool, T: DataPageSlicer>(
+    page: &DataPage,
+    bufs: &mut ColumnChunkBuffers,
+    slicer: &mut T,
+    page_row_start: usize,
+    page_row_count: usize,
+    row_group_lo: usize,
+    row_lo: usize,
+    row_hi: usize,
+    rows_filter: &[i64],
+    target_tag: ColumnTypeTag,
+) -> ParquetResult<()> {
+    match target_tag {
+        ColumnTypeTag::Decimal8 => decode_page0_filtered::<_, FILL_NULLS>(
             page,
-            bufs,
-            values_buffer,
             page_row_start,
             page_row_count,
             row_group_lo,
             row_lo,
             row_hi,
             rows_filter,
-            src_len,
+            &mut StatsPrunedDecimalColumnSink::<1, _>::new(slicer, bufs, DECIMAL8_NULL),
         ),
-        ColumnTypeTag::Decimal16 => decode_fixed_decimal_filtered_2::<FILL_NULLS>(
+        ColumnTypeTag::Decimal16 => decode_page0_filtered::<_, FILL_NULLS>(
             page,
-            bufs,
-            values_buffer,
             page_row_start,
             page_row_count,
             row_group_lo,
             row_lo,
             row_hi,
             rows_filter,
-            src_len,
+            &mut StatsPrunedDecimalColumnSink::<2, _>::new(slicer, bufs, DECIMAL16_NULL),
         ),
-        ColumnTypeTag::Decimal32 => decode_fixed_decimal_filtered_4::<FILL_NULLS>(
+        ColumnTypeTag::Decimal32 => decode_page0_filtered::<_, FILL_NULLS>(
             page,
-            bufs,
-            values_buffer,
             page_row_start,
             page_row_count,
             row_group_lo,
             row_lo,
             row_hi,
             rows_filter,
-            src_len,
+            &mut StatsPrunedDecimalColumnSink::<4, _>::new(slicer, bufs, DECIMAL32_NULL),
         ),
-        ColumnTypeTag::Decimal64 => decode_fixed_decimal_filtered_8::<FILL_NULLS>(
+        ColumnTypeTag::Decimal64 => decode_page0_filtered::<_, FILL_NULLS>(
             page,
-            bufs,
-            values_buffer,
             page_row_start,
             page_row_count,
             row_group_lo,
             row_lo,
             row_hi,
             rows_filter,
-            src_len,
+            &mut StatsPrunedDecimalColumnSink::<8, _>::new(slicer, bufs, DECIMAL64_NULL),
         ),
-        ColumnTypeTag::Decimal128 => decode_fixed_decimal_filtered_16::<FILL_NULLS>(
+        ColumnTypeTag::Decimal128 => decode_page0_filtered::<_, FILL_NULLS>(
             page,
-            bufs,
-            values_buffer,
             page_row_start,
             page_row_count,
             row_group_lo,
             row_lo,
             row_hi,
             rows_filter,
-            src_len,
+            &mut StatsPrunedDecimalColumnSink::<16, _>::new(slicer, bufs, DECIMAL128_NULL),
         ),
-        ColumnTypeTag::Decimal256 => decode_fixed_decimal_filtered_32::<FILL_NULLS>(
+        ColumnTypeTag::Decimal256 => decode_page0_filtered::<_, FILL_NULLS>(
             page,
-            bufs,
-            values_buffer,
             page_row_start,
             page_row_count,
             row_group_lo,
             row_lo,
             row_hi,
             rows_filter,
-            src_len,
+            &mut StatsPrunedDecimalColumnSink::<32, _>::new(slicer, bufs, DECIMAL256_NULL),
         ),
         _ => Err(fmt_err!(
             Unsupported,
@@ -858,45 +3152,6 @@ pub(crate) fn decode_fixed_decimal_filtered<const FILL_NULLS: bool>(
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-pub(crate) fn decode_fixed_decimal_filtered_dict<const FILL_NULLS: bool>(
-    page: &DataPage,
-    dict_page: &DictPage,
-    bufs: &mut ColumnChunkBuffers,
-    values_buffer: &[u8],
-    page_row_start: usize,
-    page_row_count: usize,
-    row_group_lo: usize,
-    row_lo: usize,
-    row_hi: usize,
-    rows_filter: &[i64],
-    src_len: usize,
-    target_tag: ColumnTypeTag,
-) -> ParquetResult<()> {
-    let dict_decoder = RuntimeFixedDictDecoder::try_new(dict_page, src_len)?;
-    let error_value = vec![0u8; src_len];
-    let mut slicer = RleDictionarySlicer::try_new(
-        values_buffer,
-        dict_decoder,
-        page_row_count,
-        page_row_count,
-        error_value.as_slice(),
-    )?;
-    decode_fixed_decimal_filtered_with_slicer::<FILL_NULLS, _>(
-        page,
-        bufs,
-        &mut slicer,
-        page_row_start,
-        page_row_count,
-        row_group_lo,
-        row_lo,
-        row_hi,
-        rows_filter,
-        src_len,
-        target_tag,
-    )
-}
-
 #[allow(clippy::too_many_arguments)]
 fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPageSlicer>(
     page: &DataPage,
@@ -909,6 +3164,12 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
     row_hi: usize,
     rows_filter: &[i64],
     src_len: usize,
+    src_scale: i32,
+    target_scale: i32,
+    overflow_policy: DecimalOverflowPolicy,
+    stats_min: Option<&[u8]>,
+    stats_max: Option<&[u8]>,
+    predicate: Option<&DecimalStatsPredicate>,
     target_tag: ColumnTypeTag,
 ) -> ParquetResult<()> {
     let target_size = match target_tag {
@@ -936,9 +3197,213 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
         ));
     }
 
+    if !decimal_stats_may_match(stats_min, stats_max, src_scale, target_scale, predicate) {
+        return decode_stats_pruned_page::<FILL_NULLS, _>(
+            page,
+            bufs,
+            slicer,
+            page_row_start,
+            page_row_count,
+            row_group_lo,
+            row_lo,
+            row_hi,
+            rows_filter,
+            target_tag,
+        );
+    }
+
     match target_tag {
         ColumnTypeTag::Decimal8 => {
-            if src_len == 1 {
+            if src_scale != target_scale {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut ScaleAdjustDecimalColumnSink::<1, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL8_NULL,
+                        src_scale,
+                        target_scale,
+                        overflow_policy,
+                    ),
+                )
+            } else if src_len == 1 {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut ReverseFixedColumnSink::<1, _>::new(slicer, bufs, DECIMAL8_NULL),
+                )
+            } else if src_len < 1 {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut SignExtendDecimalColumnSink::<1, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL8_NULL,
+                        src_len,
+                    ),
+                )
+            } else {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut NarrowingDecimalColumnSink::<1, FILL_NULLS, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL8_NULL,
+                    ),
+                )
+            }
+        }
+        ColumnTypeTag::Decimal16 => {
+            if src_scale != target_scale {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut ScaleAdjustDecimalColumnSink::<2, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL16_NULL,
+                        src_scale,
+                        target_scale,
+                        overflow_policy,
+                    ),
+                )
+            } else if src_len == 2 {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut ReverseFixedColumnSink::<2, _>::new(slicer, bufs, DECIMAL16_NULL),
+                )
+            } else if src_len < 2 {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut SignExtendDecimalColumnSink::<2, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL16_NULL,
+                        src_len,
+                    ),
+                )
+            } else {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut NarrowingDecimalColumnSink::<2, FILL_NULLS, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL16_NULL,
+                    ),
+                )
+            }
+        }
+        ColumnTypeTag::Decimal32 => {
+            if src_scale != target_scale {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut ScaleAdjustDecimalColumnSink::<4, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL32_NULL,
+                        src_scale,
+                        target_scale,
+                        overflow_policy,
+                    ),
+                )
+            } else if src_len == 4 {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut ReverseFixedColumnSink::<4, _>::new(slicer, bufs, DECIMAL32_NULL),
+                )
+            } else if src_len < 4 {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut SignExtendDecimalColumnSink::<4, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL32_NULL,
+                        src_len,
+                    ),
+                )
+            } else {
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut NarrowingDecimalColumnSink::<4, FILL_NULLS, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL32_NULL,
+                    ),
+                )
+            }
+        }
+        ColumnTypeTag::Decimal64 => {
+            if src_scale != target_scale {
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
                     page_row_start,
@@ -947,9 +3412,16 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut ReverseFixedColumnSink::<1, _>::new(slicer, bufs, DECIMAL8_NULL),
+                    &mut ScaleAdjustDecimalColumnSink::<8, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL64_NULL,
+                        src_scale,
+                        target_scale,
+                        overflow_policy,
+                    ),
                 )
-            } else {
+            } else if src_len == 8 {
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
                     page_row_start,
@@ -958,17 +3430,9 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut SignExtendDecimalColumnSink::<1, _>::new(
-                        slicer,
-                        bufs,
-                        DECIMAL8_NULL,
-                        src_len,
-                    ),
+                    &mut ReverseFixedColumnSink::<8, _>::new(slicer, bufs, DECIMAL64_NULL),
                 )
-            }
-        }
-        ColumnTypeTag::Decimal16 => {
-            if src_len == 2 {
+            } else if src_len < 8 {
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
                     page_row_start,
@@ -977,7 +3441,12 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut ReverseFixedColumnSink::<2, _>::new(slicer, bufs, DECIMAL16_NULL),
+                    &mut SignExtendDecimalColumnSink::<8, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL64_NULL,
+                        src_len,
+                    ),
                 )
             } else {
                 decode_page0_filtered::<_, FILL_NULLS>(
@@ -988,17 +3457,16 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut SignExtendDecimalColumnSink::<2, _>::new(
+                    &mut NarrowingDecimalColumnSink::<8, FILL_NULLS, _>::new(
                         slicer,
                         bufs,
-                        DECIMAL16_NULL,
-                        src_len,
+                        DECIMAL64_NULL,
                     ),
                 )
             }
         }
-        ColumnTypeTag::Decimal32 => {
-            if src_len == 4 {
+        ColumnTypeTag::Decimal128 => {
+            if src_scale != target_scale {
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
                     page_row_start,
@@ -1007,9 +3475,16 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut ReverseFixedColumnSink::<4, _>::new(slicer, bufs, DECIMAL32_NULL),
+                    &mut ScaleAdjustDecimalColumnSink::<16, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL128_NULL,
+                        src_scale,
+                        target_scale,
+                        overflow_policy,
+                    ),
                 )
-            } else {
+            } else if src_len == 16 {
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
                     page_row_start,
@@ -1018,17 +3493,9 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut SignExtendDecimalColumnSink::<4, _>::new(
-                        slicer,
-                        bufs,
-                        DECIMAL32_NULL,
-                        src_len,
-                    ),
+                    &mut WordSwapDecimalColumnSink::<16, 2, _>::new(slicer, bufs, DECIMAL128_NULL),
                 )
-            }
-        }
-        ColumnTypeTag::Decimal64 => {
-            if src_len == 8 {
+            } else if src_len < 16 {
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
                     page_row_start,
@@ -1037,7 +3504,12 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut ReverseFixedColumnSink::<8, _>::new(slicer, bufs, DECIMAL64_NULL),
+                    &mut SignExtendDecimalColumnSink::<16, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL128_NULL,
+                        src_len,
+                    ),
                 )
             } else {
                 decode_page0_filtered::<_, FILL_NULLS>(
@@ -1048,17 +3520,16 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut SignExtendDecimalColumnSink::<8, _>::new(
+                    &mut NarrowingDecimalColumnSink::<16, FILL_NULLS, _>::new(
                         slicer,
                         bufs,
-                        DECIMAL64_NULL,
-                        src_len,
+                        DECIMAL128_NULL,
                     ),
                 )
             }
         }
-        ColumnTypeTag::Decimal128 => {
-            if src_len == 16 {
+        ColumnTypeTag::Decimal256 => {
+            if src_scale != target_scale {
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
                     page_row_start,
@@ -1067,9 +3538,16 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut WordSwapDecimalColumnSink::<16, 2, _>::new(slicer, bufs, DECIMAL128_NULL),
+                    &mut ScaleAdjustDecimalColumnSink::<32, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL256_NULL,
+                        src_scale,
+                        target_scale,
+                        overflow_policy,
+                    ),
                 )
-            } else {
+            } else if src_len == 32 {
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
                     page_row_start,
@@ -1078,17 +3556,9 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut SignExtendDecimalColumnSink::<16, _>::new(
-                        slicer,
-                        bufs,
-                        DECIMAL128_NULL,
-                        src_len,
-                    ),
+                    &mut WordSwapDecimalColumnSink::<32, 4, _>::new(slicer, bufs, DECIMAL256_NULL),
                 )
-            }
-        }
-        ColumnTypeTag::Decimal256 => {
-            if src_len == 32 {
+            } else if src_len < 32 {
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
                     page_row_start,
@@ -1097,7 +3567,12 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut WordSwapDecimalColumnSink::<32, 4, _>::new(slicer, bufs, DECIMAL256_NULL),
+                    &mut SignExtendDecimalColumnSink::<32, _>::new(
+                        slicer,
+                        bufs,
+                        DECIMAL256_NULL,
+                        src_len,
+                    ),
                 )
             } else {
                 decode_page0_filtered::<_, FILL_NULLS>(
@@ -1108,11 +3583,10 @@ fn decode_fixed_decimal_filtered_with_slicer<const FILL_NULLS: bool, T: DataPage
                     row_lo,
                     row_hi,
                     rows_filter,
-                    &mut SignExtendDecimalColumnSink::<32, _>::new(
+                    &mut NarrowingDecimalColumnSink::<32, FILL_NULLS, _>::new(
                         slicer,
                         bufs,
                         DECIMAL256_NULL,
-                        src_len,
                     ),
                 )
             }
@@ -1135,6 +3609,7 @@ macro_rules! decode_fixed_decimal_impl {
             row_hi: usize,
             row_count: usize,
             src_len: usize,
+            overflow_policy: DecimalOverflowPolicy,
         ) -> ParquetResult<()> {
             if src_len == 0 {
                 return Err(fmt_err!(
@@ -1157,7 +3632,7 @@ macro_rules! decode_fixed_decimal_impl {
                         $null_value,
                     ),
                 )?;
-            } else {
+            } else if src_len < $target_size {
                 let mut slicer = DataPageDynSlicer::new(values_buffer, row_count, src_len);
                 decode_page0(
                     page,
@@ -1170,6 +3645,30 @@ macro_rules! decode_fixed_decimal_impl {
                         src_len,
                     ),
                 )?;
+            } else if overflow_policy != DecimalOverflowPolicy::Error {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, row_count, src_len);
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<$target_size, true, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                    ),
+                )?;
+            } else {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, row_count, src_len);
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<$target_size, false, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                    ),
+                )?;
             }
             Ok(())
         }
@@ -1183,6 +3682,7 @@ macro_rules! decode_fixed_decimal_impl {
             row_hi: usize,
             row_count: usize,
             src_len: usize,
+            overflow_policy: DecimalOverflowPolicy,
         ) -> ParquetResult<()> {
             if src_len == 0 {
                 return Err(fmt_err!(
@@ -1205,7 +3705,7 @@ macro_rules! decode_fixed_decimal_impl {
                         $null_value,
                     ),
                 )?;
-            } else {
+            } else if src_len < $target_size {
                 let mut slicer = DataPageDynSlicer::new(values_buffer, row_count, src_len);
                 decode_page0(
                     page,
@@ -1218,6 +3718,30 @@ macro_rules! decode_fixed_decimal_impl {
                         src_len,
                     ),
                 )?;
+            } else if overflow_policy != DecimalOverflowPolicy::Error {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, row_count, src_len);
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<$target_size, true, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                    ),
+                )?;
+            } else {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, row_count, src_len);
+                decode_page0(
+                    page,
+                    row_lo,
+                    row_hi,
+                    &mut NarrowingDecimalColumnSink::<$target_size, false, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                    ),
+                )?;
             }
             Ok(())
         }
@@ -1235,6 +3759,12 @@ macro_rules! decode_fixed_decimal_impl {
             row_hi: usize,
             rows_filter: &[i64],
             src_len: usize,
+            src_scale: i32,
+            target_scale: i32,
+            overflow_policy: DecimalOverflowPolicy,
+            stats_min: Option<&[u8]>,
+            stats_max: Option<&[u8]>,
+            predicate: Option<&DecimalStatsPredicate>,
         ) -> ParquetResult<()> {
             if src_len == 0 {
                 return Err(fmt_err!(
@@ -1245,7 +3775,43 @@ macro_rules! decode_fixed_decimal_impl {
                     $target_size
                 ));
             }
-            if src_len == $target_size {
+            if !decimal_stats_may_match(stats_min, stats_max, src_scale, target_scale, predicate) {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
+                return decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut StatsPrunedDecimalColumnSink::<$target_size, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                    ),
+                );
+            }
+            if src_scale != target_scale {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut ScaleAdjustDecimalColumnSink::<$target_size, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                        src_scale,
+                        target_scale,
+                        overflow_policy,
+                    ),
+                )?;
+            } else if src_len == $target_size {
                 let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
@@ -1261,7 +3827,7 @@ macro_rules! decode_fixed_decimal_impl {
                         $null_value,
                     ),
                 )?;
-            } else {
+            } else if src_len < $target_size {
                 let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
@@ -1278,6 +3844,22 @@ macro_rules! decode_fixed_decimal_impl {
                         src_len,
                     ),
                 )?;
+            } else {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut NarrowingDecimalColumnSink::<$target_size, FILL_NULLS, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                    ),
+                )?;
             }
             Ok(())
         }
@@ -1295,6 +3877,12 @@ macro_rules! decode_fixed_decimal_impl {
             row_hi: usize,
             rows_filter: &[i64],
             src_len: usize,
+            src_scale: i32,
+            target_scale: i32,
+            overflow_policy: DecimalOverflowPolicy,
+            stats_min: Option<&[u8]>,
+            stats_max: Option<&[u8]>,
+            predicate: Option<&DecimalStatsPredicate>,
         ) -> ParquetResult<()> {
             if src_len == 0 {
                 return Err(fmt_err!(
@@ -1305,7 +3893,43 @@ macro_rules! decode_fixed_decimal_impl {
                     $target_size
                 ));
             }
-            if src_len == $target_size {
+            if !decimal_stats_may_match(stats_min, stats_max, src_scale, target_scale, predicate) {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
+                return decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut StatsPrunedDecimalColumnSink::<$target_size, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                    ),
+                );
+            }
+            if src_scale != target_scale {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut ScaleAdjustDecimalColumnSink::<$target_size, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                        src_scale,
+                        target_scale,
+                        overflow_policy,
+                    ),
+                )?;
+            } else if src_len == $target_size {
                 let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
@@ -1321,7 +3945,7 @@ macro_rules! decode_fixed_decimal_impl {
                         $null_value,
                     ),
                 )?;
-            } else {
+            } else if src_len < $target_size {
                 let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
                 decode_page0_filtered::<_, FILL_NULLS>(
                     page,
@@ -1338,6 +3962,22 @@ macro_rules! decode_fixed_decimal_impl {
                         src_len,
                     ),
                 )?;
+            } else {
+                let mut slicer = DataPageDynSlicer::new(values_buffer, page_row_count, src_len);
+                decode_page0_filtered::<_, FILL_NULLS>(
+                    page,
+                    page_row_start,
+                    page_row_count,
+                    row_group_lo,
+                    row_lo,
+                    row_hi,
+                    rows_filter,
+                    &mut NarrowingDecimalColumnSink::<$target_size, FILL_NULLS, _>::new(
+                        &mut slicer,
+                        bufs,
+                        $null_value,
+                    ),
+                )?;
             }
             Ok(())
         }
@@ -1380,3 +4020,142 @@ decode_fixed_decimal_impl!(
     DECIMAL256_NULL,
     "Decimal256"
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_decimal_be<const N: usize>(be_bytes: &[u8]) -> [u8; N] {
+        let mut dest = [0u8; N];
+        unsafe {
+            convert_be_decimal_bytes::<N>(be_bytes, dest.as_mut_ptr()).unwrap();
+        }
+        dest
+    }
+
+    #[test]
+    fn rescale_widen_multiplies_by_scale_delta() {
+        let mut buf = 123i32.to_le_bytes();
+        rescale_decimal_in_place(&mut buf, &DECIMAL32_NULL, 2, 4).unwrap();
+        assert_eq!(i32::from_le_bytes(buf), 12_300);
+    }
+
+    #[test]
+    fn rescale_widen_negative_value() {
+        let mut buf = (-123i32).to_le_bytes();
+        rescale_decimal_in_place(&mut buf, &DECIMAL32_NULL, 2, 4).unwrap();
+        assert_eq!(i32::from_le_bytes(buf), -12_300);
+    }
+
+    #[test]
+    fn rescale_narrow_rounds_down_below_half() {
+        let mut buf = 124i32.to_le_bytes();
+        rescale_decimal_in_place(&mut buf, &DECIMAL32_NULL, 3, 2).unwrap();
+        assert_eq!(i32::from_le_bytes(buf), 12);
+    }
+
+    #[test]
+    fn rescale_narrow_rounds_up_above_half() {
+        let mut buf = 126i32.to_le_bytes();
+        rescale_decimal_in_place(&mut buf, &DECIMAL32_NULL, 3, 2).unwrap();
+        assert_eq!(i32::from_le_bytes(buf), 13);
+    }
+
+    #[test]
+    fn rescale_narrow_round_half_even_stays_at_even_quotient() {
+        // 125 / 10 = 12 remainder 5 (exact tie); 12 is already even, so it stays 12.
+        let mut buf = 125i32.to_le_bytes();
+        rescale_decimal_in_place(&mut buf, &DECIMAL32_NULL, 3, 2).unwrap();
+        assert_eq!(i32::from_le_bytes(buf), 12);
+    }
+
+    #[test]
+    fn rescale_narrow_round_half_even_rounds_up_to_even_quotient() {
+        // 135 / 10 = 13 remainder 5 (exact tie); 13 is odd, so it rounds up to 14.
+        let mut buf = 135i32.to_le_bytes();
+        rescale_decimal_in_place(&mut buf, &DECIMAL32_NULL, 3, 2).unwrap();
+        assert_eq!(i32::from_le_bytes(buf), 14);
+    }
+
+    #[test]
+    fn rescale_narrow_negative_round_half_even() {
+        let mut buf = (-135i32).to_le_bytes();
+        rescale_decimal_in_place(&mut buf, &DECIMAL32_NULL, 3, 2).unwrap();
+        assert_eq!(i32::from_le_bytes(buf), -14);
+    }
+
+    #[test]
+    fn rescale_widen_overflow_errors() {
+        let mut buf = (i32::MAX / 10).to_le_bytes();
+        assert!(rescale_decimal_in_place(&mut buf, &DECIMAL32_NULL, 0, 2).is_err());
+    }
+
+    #[test]
+    fn rescale_null_sentinel_passes_through_untouched() {
+        let mut buf = DECIMAL32_NULL;
+        rescale_decimal_in_place(&mut buf, &DECIMAL32_NULL, 2, 4).unwrap();
+        assert_eq!(buf, DECIMAL32_NULL);
+    }
+
+    #[test]
+    fn rescale_widen_decimal128_word_swapped_layout() {
+        let mut buf = build_decimal_be::<16>(&1i64.to_be_bytes());
+        rescale_decimal_in_place(&mut buf, &DECIMAL128_NULL, 0, 20).unwrap();
+        swap_decimal_word_order(&mut buf);
+        assert_eq!(i128::from_le_bytes(buf), 100_000_000_000_000_000_000i128);
+    }
+
+    #[test]
+    fn rescale_narrow_decimal128_round_half_even() {
+        let mut buf = build_decimal_be::<16>(&125i64.to_be_bytes());
+        rescale_decimal_in_place(&mut buf, &DECIMAL128_NULL, 3, 2).unwrap();
+        swap_decimal_word_order(&mut buf);
+        assert_eq!(i128::from_le_bytes(buf), 12);
+    }
+
+    #[test]
+    fn prematerialized_table_values_survive_verbatim_copy_across_all_widths() {
+        // The prematerialized dictionary table holds each distinct value already in final
+        // N-byte QuestDB layout; copying those N bytes straight into the column buffer (as
+        // `PrematerializedDecimalColumnSink` does) must be a lossless no-op for every width.
+        macro_rules! check {
+            ($n:literal, $be:expr) => {{
+                let table_value = build_decimal_be::<$n>($be);
+                let mut copied = [0u8; $n];
+                unsafe {
+                    ptr::copy_nonoverlapping(table_value.as_ptr(), copied.as_mut_ptr(), $n);
+                }
+                assert_eq!(copied, table_value, "verbatim {}-byte copy must be lossless", $n);
+            }};
+        }
+        check!(1, &42i8.to_be_bytes());
+        check!(2, &(-1234i16).to_be_bytes());
+        check!(4, &123_456i32.to_be_bytes());
+        check!(8, &(-987_654_321i64).to_be_bytes());
+        check!(16, &i64::MIN.to_be_bytes());
+        check!(32, &i64::MIN.to_be_bytes());
+    }
+
+    #[test]
+    fn int_decimal_column_sink_must_not_be_reused_for_prematerialized_wide_values() {
+        // Regression test for chunk0-5: `IntDecimalColumnSink::<N, N, _>::convert_decimal`
+        // assumes `src` is a native little-endian integer no wider than its on-disk layout,
+        // sign-extending it into `N` bytes. An already-final-layout prematerialized table
+        // value does not fit that assumption for N > 8, so reusing this sink corrupts it
+        // instead of leaving it unchanged — exactly why `PrematerializedDecimalColumnSink`
+        // (a plain N-byte copy) must be used for that path instead.
+        let table_value = build_decimal_be::<16>(&(-42i64).to_be_bytes());
+        let mut via_int_sink = [0u8; 16];
+        unsafe {
+            IntDecimalColumnSink::<16, 16, DataPageDynSlicer>::convert_decimal(
+                &table_value,
+                via_int_sink.as_mut_ptr(),
+            );
+        }
+        assert_ne!(
+            via_int_sink, table_value,
+            "IntDecimalColumnSink::convert_decimal corrupts an already-final wide decimal value, \
+             demonstrating why the prematerialized path cannot reuse it"
+        );
+    }
+}